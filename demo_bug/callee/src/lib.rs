@@ -7,6 +7,7 @@ compile_error!(
 static STATIC_OPT_U8_X: Option<u8> = Some(b'X');
 static CROSS_STR: &str = "CROSS";
 static CROSS_BYTES: &[u8] = b"Cross\0".as_slice();
+static STATIC_BOOL_TRUE: bool = true;
 
 pub fn print_static_option_u8() {
     println!("{:?}", &STATIC_OPT_U8_X as *const Option<u8>);
@@ -19,3 +20,7 @@ pub fn print_static_str() {
 pub fn print_static_bytes() {
     println!("{:?}", CROSS_BYTES.as_ptr());
 }
+
+pub fn print_static_bool() {
+    println!("{:?}", &STATIC_BOOL_TRUE as *const bool);
+}
@@ -4,7 +4,7 @@ compile_error!(
 );
 
 use core::ffi::CStr;
-use ndd::{NonDeDuplicated, NonDeDuplicatedCStr, NonDeDuplicatedStr};
+use ndd::{NonDeDuplicated, NonDeDuplicatedBool, NonDeDuplicatedCStr, NonDeDuplicatedStr};
 
 // Intentionally NOT public, to see if these `static` variables do get shared cross-crate anyway.
 static STATIC_OPT_U8_X_NDD: NonDeDuplicated<Option<u8>> = NonDeDuplicated::new(Some(b'X'));
@@ -16,6 +16,9 @@ static CROSS_STR: &str = CROSS_STR_NDD.get();
 static CROSS_CSTR_NDD: NonDeDuplicatedCStr<6> = NonDeDuplicatedCStr::new_from_bytes(*b"Cross\0");
 static CROSS_CSTR: &CStr = CROSS_CSTR_NDD.get();
 
+static STATIC_BOOL_TRUE_NDD: NonDeDuplicatedBool = NonDeDuplicated::new(true);
+static STATIC_BOOL_TRUE: &bool = STATIC_BOOL_TRUE_NDD.get();
+
 pub fn print_static_option_u8() {
     println!("{:?}", STATIC_OPT_U8_X as *const Option<u8>);
 }
@@ -27,3 +30,7 @@ pub fn print_static_str() {
 pub fn print_static_bytes() {
     println!("{:?}", CROSS_CSTR.as_ptr());
 }
+
+pub fn print_static_bool() {
+    println!("{:?}", STATIC_BOOL_TRUE as *const bool);
+}
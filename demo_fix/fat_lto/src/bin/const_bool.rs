@@ -0,0 +1 @@
+../../../../demo_shared_src/const_bool.rs
\ No newline at end of file
@@ -0,0 +1,27 @@
+//! Build-only demo that `ndd` works without `std` (and without `alloc`). Build it for a bare metal
+//! target, for example:
+//!
+//! ```sh
+//! rustup target add thumbv7em-none-eabihf
+//! cargo build --target thumbv7em-none-eabihf
+//! ```
+//!
+//! Any `std` (or `alloc`) usage in `ndd` (other than behind its features) makes that build fail.
+#![no_std]
+
+use core::ffi::CStr;
+use ndd::{NonDeDuplicated, NonDeDuplicatedCStr, NonDeDuplicatedStr};
+
+static U32_NDD: NonDeDuplicated<u32> = NonDeDuplicated::new(0xC0FFEE);
+static U32_REF: &u32 = U32_NDD.get();
+
+static STR_NDD: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("Bare!");
+static STR_REF: &str = STR_NDD.get();
+
+static CSTR_NDD: NonDeDuplicatedCStr<6> = NonDeDuplicatedCStr::new(c"Metal");
+static CSTR_REF: &CStr = CSTR_NDD.get();
+
+/// Whether the given references are the designated `static` variables (compared by address).
+pub fn is_designated(u: &u32, s: &str, c: &CStr) -> bool {
+    core::ptr::eq(u, U32_REF) && core::ptr::eq(s, STR_REF) && core::ptr::eq(c, CSTR_REF)
+}
@@ -0,0 +1,7 @@
+const CONST_BOOL_TRUE: bool = true;
+
+fn main() {
+    callee::print_static_bool();
+
+    println!("{:?}", &CONST_BOOL_TRUE as *const bool);
+}
@@ -0,0 +1,183 @@
+//! Opt-in global registry of addresses of `ndd` `static` variables. Use it to verify (in a live
+//! binary) that no two registered instances share an address.
+//!
+//! Registration is explicit (see [crate::NonDeDuplicatedFlexible::register]), because all `ndd`
+//! constructors (and `get()` functions) are `const`.
+//!
+//! Requires `std` feature. With `tracing` feature, the first `register()` of each address also
+//! emits a `tracing` event (at `TRACE` level) with that address.
+//!
+//! With `debug-verify` feature (in debug builds only), [crate::NonDeDuplicatedFlexible::verify]
+//! checks that no address is seen from two different `ndd` types.
+
+use alloc::vec::Vec;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static REGISTRY: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+/// Addresses already traced. Separate from [REGISTRY], because tracing doesn't depend on [enable].
+#[cfg(feature = "tracing")]
+static TRACED: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+/// Addresses (and types) already verified. Separate from [REGISTRY], because verification
+/// doesn't depend on [enable].
+#[cfg(all(feature = "debug-verify", debug_assertions))]
+static VERIFIED: Mutex<Vec<(usize, core::any::TypeId, &'static str)>> = Mutex::new(Vec::new());
+
+/// Start collecting addresses. Any earlier calls to `register()` are not recorded.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Whether [enable] has been called.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Addresses registered so far, in order of registration. A repeated address means either a
+/// collision, or that the same `static` got registered more than once.
+pub fn registered_addresses() -> Vec<usize> {
+    REGISTRY.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+pub(crate) fn register(addr: usize) {
+    #[cfg(feature = "tracing")]
+    trace_once(addr);
+    if is_enabled() {
+        REGISTRY
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(addr);
+    }
+}
+
+#[cfg(all(feature = "debug-verify", debug_assertions))]
+pub(crate) fn verify(addr: usize, type_id: core::any::TypeId, type_name: &'static str) {
+    let mut verified = VERIFIED.lock().unwrap_or_else(|e| e.into_inner());
+    match verified.iter().find(|(a, _, _)| *a == addr) {
+        Some((_, seen_id, seen_name)) => assert!(
+            *seen_id == type_id,
+            "Address {:#x} seen from two different ndd types: {} and {}.",
+            addr,
+            seen_name,
+            type_name
+        ),
+        None => verified.push((addr, type_id, type_name)),
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn trace_once(addr: usize) {
+    let mut traced = TRACED.lock().unwrap_or_else(|e| e.into_inner());
+    if !traced.contains(&addr) {
+        traced.push(addr);
+        tracing::trace!(addr, "ndd static registered");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonDeDuplicated, NonDeDuplicatedCStr, NonDeDuplicatedStr};
+
+    static U8_NDD_1: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+    static U8_NDD_2: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+    static STR_NDD: NonDeDuplicatedStr<1> = NonDeDuplicatedStr::new("R");
+    static CSTR_NDD: NonDeDuplicatedCStr<2> = NonDeDuplicatedCStr::new_from_bytes(*b"R\0");
+
+    #[test]
+    fn registered_addresses_distinct() {
+        super::enable();
+        U8_NDD_1.register();
+        U8_NDD_2.register();
+        STR_NDD.register();
+        CSTR_NDD.register();
+
+        // Other tests (running in parallel) may register other addresses, too. So check only for
+        // ours.
+        let addresses = super::registered_addresses();
+        let mut ours = [
+            U8_NDD_1.get() as *const u8 as usize,
+            U8_NDD_2.get() as *const u8 as usize,
+            STR_NDD.get().as_ptr() as usize,
+            CSTR_NDD.get().as_ptr() as usize,
+        ];
+        for addr in ours {
+            assert!(addresses.contains(&addr));
+        }
+        ours.sort_unstable();
+        assert!(ours.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[cfg(all(feature = "debug-verify", debug_assertions))]
+    mod verify_tests {
+        use core::any::TypeId;
+
+        #[test]
+        fn verify_same_static_twice() {
+            super::U8_NDD_1.verify();
+            super::U8_NDD_1.verify();
+            super::STR_NDD.verify();
+        }
+
+        /// Distinct `static` variables never share an address (except for zero-sized ones, maybe).
+        /// So simulate that.
+        #[test]
+        #[should_panic(expected = "seen from two different ndd types: u8 and u16.")]
+        fn verify_collision() {
+            let local = 0u8;
+            let addr = &local as *const u8 as usize;
+            crate::diagnostics::verify(addr, TypeId::of::<u8>(), "u8");
+            crate::diagnostics::verify(addr, TypeId::of::<u16>(), "u16");
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    mod tracing_tests {
+        use crate::NonDeDuplicated;
+        use std::string::{String, ToString};
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Collects values of `addr` fields of any events.
+        struct AddrCollector(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for &AddrCollector {
+            fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                if field.name() == "addr" {
+                    self.0.lock().unwrap().push(std::format!("{value:?}"));
+                }
+            }
+        }
+
+        impl Subscriber for AddrCollector {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut &*self);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        static U8_NDD_TRACED: NonDeDuplicated<u8> = NonDeDuplicated::new(b'T');
+
+        #[test]
+        fn traced_on_first_register_only() {
+            let addrs = Arc::new(Mutex::new(Vec::new()));
+            tracing::subscriber::with_default(AddrCollector(addrs.clone()), || {
+                U8_NDD_TRACED.register();
+                U8_NDD_TRACED.register();
+            });
+            let expected = (U8_NDD_TRACED.get() as *const u8 as usize).to_string();
+            assert_eq!(*addrs.lock().unwrap(), [expected]);
+        }
+    }
+}
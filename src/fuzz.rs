@@ -0,0 +1,52 @@
+//! Entry points for fuzzing (with `cargo fuzz`, see `fuzz/` directory in the repository).
+//!
+//! Requires `fuzzing` feature. Not for production use.
+
+use crate::NonDeDuplicatedCStr;
+
+/// Try to construct a [NonDeDuplicatedCStr] from arbitrary `bytes` through the fallible path
+/// ([TryFrom]). Return `Some(())` if `bytes` got accepted, `None` if rejected.
+///
+/// Rejecting must not panic. Panic only if an accepted instance doesn't round-trip (that would be
+/// a bug in `ndd`).
+///
+/// `N` is a const generic, so it has to be chosen at compile time. Only lengths `1..=32` are
+/// tried. Any other lengths are rejected.
+pub fn build_cstr(bytes: &[u8]) -> Option<()> {
+    macro_rules! by_len {
+        ($($n:literal)*) => {
+            match bytes.len() {
+                $($n => build_cstr_n::<$n>(bytes),)*
+                _ => None,
+            }
+        };
+    }
+    by_len!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32)
+}
+
+fn build_cstr_n<const N: usize>(bytes: &[u8]) -> Option<()> {
+    let cstr = NonDeDuplicatedCStr::<N>::try_from(bytes).ok()?;
+    assert_eq!(cstr.get().to_bytes_with_nul(), bytes);
+    // Not a `static`, so don't drop it (that panics in debug builds).
+    core::mem::forget(cstr);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_cstr;
+
+    #[test]
+    fn accepted() {
+        assert_eq!(build_cstr(b"\0"), Some(()));
+        assert_eq!(build_cstr(b"Fuzz\0"), Some(()));
+    }
+
+    #[test]
+    fn rejected() {
+        assert_eq!(build_cstr(b""), None);
+        assert_eq!(build_cstr(b"Fuzz"), None);
+        assert_eq!(build_cstr(b"Fu\0z\0"), None);
+        assert_eq!(build_cstr(&[b'F'; 33]), None);
+    }
+}
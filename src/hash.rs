@@ -0,0 +1,71 @@
+//! A cheap [Hasher] for keys that are unique addresses, like [crate::AddrHashed]. `no_std`
+//! friendly.
+//!
+//! ```rust
+//! use ndd::hash::BuildIdentityHasher;
+//! use ndd::{AddrHashed, NonDeDuplicated};
+//! use std::collections::HashMap;
+//!
+//! static FIRST: NonDeDuplicated<u8> = NonDeDuplicated::new(1);
+//! let mut map = HashMap::with_hasher(BuildIdentityHasher::default());
+//! map.insert(AddrHashed(&FIRST), "first");
+//! assert_eq!(map[&AddrHashed(&FIRST)], "first");
+//! ```
+
+use core::hash::{BuildHasherDefault, Hasher};
+
+/// Fractional part of the golden ratio, for Fibonacci hashing.
+const MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A [Hasher] that keeps the (address) value written to it, rather than re-hashing it
+/// thoroughly. [Hasher::finish] only multiplies it by a constant, so that (aligned) addresses
+/// with trailing zero bits still spread over the whole hash (which hash tables use both ends of).
+///
+/// Intended for [crate::AddrHashed] only, which writes one `usize`. Other input, written with
+/// [Hasher::write], is folded in byte-by-byte (correctly, but not fast).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdentityHasher(u64);
+
+impl Hasher for IdentityHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+        }
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.0 ^= i as u64;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0.wrapping_mul(MULTIPLIER)
+    }
+}
+
+/// For `HashMap::with_hasher` (and similar).
+pub type BuildIdentityHasher = BuildHasherDefault<IdentityHasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::BuildIdentityHasher;
+    use crate::{AddrHashed, NonDeDuplicated};
+    use std::collections::HashMap;
+
+    static U8_NDD_1: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+    static U8_NDD_2: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+    static U8_NDD_3: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+
+    // A false positive: AddrHashed hashes by address only, so the inner Cell doesn't matter.
+    #[allow(clippy::mutable_key_type)]
+    #[test]
+    fn lookups() {
+        let mut map = HashMap::with_hasher(BuildIdentityHasher::default());
+        map.insert(AddrHashed(&U8_NDD_1), 1);
+        map.insert(AddrHashed(&U8_NDD_2), 2);
+        map.insert(AddrHashed(&U8_NDD_3), 3);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&AddrHashed(&U8_NDD_1)], 1);
+        assert_eq!(map[&AddrHashed(&U8_NDD_2)], 2);
+        assert_eq!(map[&AddrHashed(&U8_NDD_3)], 3);
+    }
+}
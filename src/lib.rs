@@ -28,6 +28,7 @@
 //! [`core::ops::Deref`]: core::ops::Deref
 //! [`core::convert::From`]: core::convert::From
 //!
+//! [`demo_no_std/`]: https://github.com/peter-lyons-kehl/ndd/tree/main/demo_no_std/
 //! [`demo_bug/non_lto`]: https://github.com/peter-lyons-kehl/ndd/tree/main/demo_bug/non_lto/
 //! [`demo_bug/fat_lto`]: https://github.com/peter-lyons-kehl/ndd/tree/main/demo_bug/fat_lto/
 //! [`demo_fix/fat_lto`]: https://github.com/peter-lyons-kehl/ndd/tree/main/demo_fix/fat_lto/
@@ -39,11 +40,20 @@
 //! [`const_str`]: https://github.com/peter-lyons-kehl/ndd/blob/main/demo_shared_src/const_str.rs
 //! [`const_opt`]: https://github.com/peter-lyons-kehl/ndd/blob/main/demo_shared_src/const_opt.rs
 //! [`const_u8s`]: https://github.com/peter-lyons-kehl/ndd/blob/main/demo_shared_src/const_u8s.rs
+//! [`const_bool`]: https://github.com/peter-lyons-kehl/ndd/blob/main/demo_shared_src/const_bool.rs
 #![doc = include_str!("../README.md")]
-#![cfg_attr(not(any(doc, test)), no_std)]
-#[cfg(doc)]
+#![cfg_attr(not(any(doc, test, feature = "std")), no_std)]
+#[cfg(any(doc, feature = "alloc"))]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+pub mod diagnostics;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+pub mod hash;
+mod macros;
+pub mod testing;
+
 use core::any::Any;
 use core::cell::Cell;
 use core::ffi::CStr;
@@ -83,6 +93,31 @@ use core::marker::PhantomData;
 /// generic parameter `T`) to implement [Any] the first example above fails, too. That prevents
 /// mistakes earlier.
 ///
+/// [Any] is implemented for **all** `'static` types. So this requirement doesn't exclude any type
+/// that a `'static` bound would allow. Generic code only needs `'static` (plus [Send] and [Sync]):
+/// ```rust
+/// use ndd::NonDeDuplicated;
+///
+/// const fn wrap<T: 'static + Send + Sync>(value: T) -> NonDeDuplicated<T> {
+///     NonDeDuplicated::new(value)
+/// }
+/// static U8_NDD: NonDeDuplicated<u8> = wrap(1);
+/// ```
+///
+/// It intentionally does **not** implement [Clone] (nor [Copy]). A clone would be a second instance
+/// that could be mistaken for the same `static`. So accidental duplication fails at compile time
+/// (rather than panicking at runtime):
+/// ```rust,compile_fail
+/// fn expect_clone<T: Clone>() {}
+/// expect_clone::<ndd::NonDeDuplicated<u8>>();
+/// ```
+/// To have another instance, declare a separate `static`.
+///
+/// It is `#[repr(transparent)]` (and so are [Cell] and [core::cell::UnsafeCell] inside). So it has
+/// the same layout and ABI as `OWN`. For FFI, a `static` [NonDeDuplicated]`<T>` (with a
+/// `#[repr(C)]` `T`) can be declared on the C side simply as `T`. (A `#[repr(C)]` wrapper would
+/// guarantee less: the same layout, but not the same ABI. So there is no such variant.)
+///
 /// Do not use [NonDeDuplicatedFlexible] directly. Instead, use [NonDeDuplicated],
 /// [NonDeDuplicatedStr] and [NonDeDuplicatedCStr].
 #[repr(transparent)]
@@ -91,8 +126,75 @@ pub struct NonDeDuplicatedFlexible<OWN: Any + Send + Sync, TO: Any + Send + Sync
     _t: PhantomData<TO>,
 }
 
+impl<OWN: Any + Send + Sync, TO: Any + Send + Sync + ?Sized> NonDeDuplicatedFlexible<OWN, TO> {
+    /// Register the address of this (`static`) instance with [diagnostics], if
+    /// [diagnostics::enable] has been called. Otherwise this is a no-op.
+    ///
+    /// Constructors are `const`, so they can't register anything themselves. Call this once per
+    /// `static` (for example, at the start of `main`).
+    ///
+    /// With `tracing` feature, the first call for any given address also emits a `tracing` event
+    /// (even if [diagnostics::enable] has not been called).
+    #[cfg(feature = "std")]
+    pub fn register(&'static self) {
+        diagnostics::register(self.cell.as_ptr() as usize);
+    }
+
+    /// With `debug-verify` feature, in debug builds: Assert that the address of this (`static`)
+    /// instance has not been seen (by an earlier `verify()`) from a different `ndd` type. That
+    /// catches accidental aliasing during development. Otherwise this is a no-op.
+    ///
+    /// Like `register()`, call this once per `static`.
+    #[inline]
+    pub fn verify(&'static self) {
+        #[cfg(all(feature = "debug-verify", debug_assertions))]
+        diagnostics::verify(
+            self.as_erased_ptr() as usize,
+            core::any::TypeId::of::<Self>(),
+            core::any::type_name::<Self>(),
+        );
+    }
+
+    /// The address of the inner data, with its type erased. For comparing addresses across
+    /// different `ndd` types (like in a heterogeneous collection of `static` variables).
+    pub const fn as_erased_ptr(&self) -> *const () {
+        self.cell.as_ptr().cast_const().cast()
+    }
+
+    /// Whether `self` and `other` are the same instance (compared by address, with
+    /// [core::ptr::eq]). Two distinct `static` variables are never `ref_eq`, even if equal in
+    /// value.
+    pub fn ref_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+
+impl<OWN: Any + Send + Sync + PartialEq, TO: Any + Send + Sync + ?Sized>
+    NonDeDuplicatedFlexible<OWN, TO>
+{
+    /// Whether `self` and `other` are equal in value (regardless of their addresses). Any
+    /// [PartialEq] implemented by `ndd` is the same as this.
+    pub fn val_eq(&self, other: &Self) -> bool {
+        let ptr = self.cell.as_ptr();
+        let other_ptr = other.cell.as_ptr();
+        unsafe { *ptr == *other_ptr }
+    }
+}
+
 /// For non-de-duplicated objects stored in `static` variables. NOT for string slices - for those
 /// use [NonDeDuplicatedStr] and [NonDeDuplicatedCStr].
+///
+/// Unlike [Cell], there is no mutation API - no `set`, `replace`, `take` nor `get_mut` - and there
+/// never will be. `ndd` is for immutable `static` data with unique addresses. (For mutable
+/// `static` data use atomics or a `Mutex` instead.) So these fail to compile:
+/// ```rust,compile_fail
+/// static U8_NDD: ndd::NonDeDuplicated<u8> = ndd::NonDeDuplicated::new(1);
+/// U8_NDD.set(2);
+/// ```
+/// ```rust,compile_fail
+/// let mut u8_ndd = ndd::NonDeDuplicated::new(1u8);
+/// *u8_ndd.get_mut() = 2;
+/// ```
 #[allow(type_alias_bounds)]
 pub type NonDeDuplicated<T: Any + Send + Sync> = NonDeDuplicatedFlexible<T, T>;
 
@@ -108,16 +210,438 @@ impl<T: Any + Send + Sync> NonDeDuplicated<T> {
     }
 
     /// Get a reference.
+    #[inline]
     pub const fn get(&self) -> &T {
         let ptr = self.cell.as_ptr();
         unsafe { &*ptr }
     }
+
+    /// The address and the reference, together. For terser tables in tests. Not `const`, because
+    /// `const` can't cast pointers to integers.
+    pub fn addr_and_ref(&self) -> (usize, &T) {
+        (self.as_erased_ptr() as usize, self.get())
+    }
+
+    /// Get a raw pointer. Usable in `const` (and `static`) initializers, like
+    /// [NonDeDuplicated::get].
+    ///
+    /// A `*const T` is not [Sync], so it can't be the type of a `static` directly. Assign it to a
+    /// `const`, or wrap it (in a type that is `Sync`) for a `static`.
+    /// ```rust
+    /// use ndd::NonDeDuplicated;
+    ///
+    /// static X_NDD: NonDeDuplicated<u8> = NonDeDuplicated::new(7);
+    /// const X_PTR: *const u8 = X_NDD.as_ptr_const();
+    /// assert_eq!(unsafe { *X_PTR }, 7);
+    /// ```
+    #[inline]
+    pub const fn as_ptr_const(&self) -> *const T {
+        self.cell.as_ptr().cast_const()
+    }
+
+    /// Move `self` to heap and leak it, so that it has a unique `'static` address. For instances
+    /// built at runtime (which can't be assigned to a `static`): `Drop::drop` panics in debug/miri
+    /// builds, so they must not be dropped. Every call allocates (and leaks).
+    ///
+    /// Requires `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub fn into_ref(self) -> &'static T {
+        alloc::boxed::Box::leak(alloc::boxed::Box::new(self)).get()
+    }
 }
 
-/// Separate from [bytes_to_array], so that we help monomorphization surface area to be smaller.
-const fn copy_bytes_to_array(to: &mut [u8], from: &[u8], len: usize) {
-    if from.len() > len {
-        let msg = match from.len() - len {
+/// So that methods of `T` can be called directly. Not `const` (on `stable`), so in `const` contexts
+/// use [NonDeDuplicated::get] instead.
+///
+/// Methods of the wrapper (like [NonDeDuplicated::get] or [NonDeDuplicatedFlexible::ref_eq]) take
+/// precedence over methods of `T` with the same name. Call those through `MY_STATIC.get().get()`
+/// or `(*MY_STATIC).get()`.
+impl<T: Any + Send + Sync> core::ops::Deref for NonDeDuplicated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
+
+/// Format the inner value (as if it were not wrapped).
+impl<T: Any + Send + Sync + core::fmt::Display> core::fmt::Display for NonDeDuplicated<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+/// Compare by value (and not by address), like [NonDeDuplicatedFlexible::val_eq]. For comparison
+/// by address use [NonDeDuplicatedFlexible::ref_eq].
+impl<T: Any + Send + Sync + PartialEq> PartialEq for NonDeDuplicated<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.val_eq(other)
+    }
+}
+
+/// Only if `T: Eq`. So not for floating-point payloads (like `f32`), since `NaN != NaN`.
+impl<T: Any + Send + Sync + Eq> Eq for NonDeDuplicated<T> {}
+
+/// Hash by value, consistent with [PartialEq] (so that these can be keys of a `HashMap`). To hash
+/// by address use [AddrHashed] instead.
+impl<T: Any + Send + Sync + core::hash::Hash> core::hash::Hash for NonDeDuplicated<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+/// Compare the inner value with `other`: `assert_eq!(MY_STATIC, &value)`.
+impl<T: Any + Send + Sync + PartialEq> PartialEq<&T> for NonDeDuplicated<T> {
+    fn eq(&self, other: &&T) -> bool {
+        self.get() == *other
+    }
+}
+
+/// Order by value (and not by address). To sort, collect references to the `static` variables,
+/// like `Vec<&NonDeDuplicated<T>>`.
+impl<T: Any + Send + Sync + PartialOrd> PartialOrd for NonDeDuplicated<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.get().partial_cmp(other.get())
+    }
+}
+
+/// Order by value (and not by address).
+impl<T: Any + Send + Sync + Ord> Ord for NonDeDuplicated<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.get().cmp(other.get())
+    }
+}
+
+/// Show the type names of `OWN` and `TO` (as per [core::any::type_name]), and the address. Not the
+/// value (so this doesn't require `OWN: Debug`). So `{:?}` never reveals the contents (like of an
+/// interned secret). There is no feature to redact it further.
+impl<OWN: Any + Send + Sync, TO: Any + Send + Sync + ?Sized> core::fmt::Debug
+    for NonDeDuplicatedFlexible<OWN, TO>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NonDeDuplicatedFlexible")
+            .field("own", &format_args!("{}", core::any::type_name::<OWN>()))
+            .field("to", &format_args!("{}", core::any::type_name::<TO>()))
+            .field("addr", &self.cell.as_ptr())
+            .finish()
+    }
+}
+
+/// A key that hashes and compares a [NonDeDuplicated] by its address (and not by its value). Two
+/// distinct `static` variables are two distinct keys, even if equal in value. For a cheap hasher
+/// for such keys, see [hash::BuildIdentityHasher].
+///
+/// Clippy's `mutable_key_type` lint may warn about collections with this as the key type. That is a
+/// false positive: The inner [core::cell::Cell] is never mutated, and only the address is hashed.
+pub struct AddrHashed<'a, T: Any + Send + Sync>(pub &'a NonDeDuplicated<T>);
+
+impl<T: Any + Send + Sync> Clone for AddrHashed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: Any + Send + Sync> Copy for AddrHashed<'_, T> {}
+
+impl<T: Any + Send + Sync> PartialEq for AddrHashed<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ref_eq(other.0)
+    }
+}
+impl<T: Any + Send + Sync> Eq for AddrHashed<'_, T> {}
+
+impl<T: Any + Send + Sync> core::hash::Hash for AddrHashed<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::ptr::hash(self.0, state)
+    }
+}
+
+/// For non-de-duplicated references (to existing `'static` data) stored in `static` variables. The
+/// pointee is **not** copied, so it may be shared (with other references, or it may itself be
+/// de-duplicated). Only the slot holding the reference has a unique address (see
+/// [NonDeDuplicatedFlexible::as_erased_ptr]).
+#[allow(type_alias_bounds)]
+pub type NonDeDuplicatedRef<T: Any + Send + Sync + ?Sized> = NonDeDuplicatedFlexible<&'static T, T>;
+impl<T: Any + Send + Sync + ?Sized> NonDeDuplicatedRef<T> {
+    /// Construct a new instance.
+    pub const fn new(r: &'static T) -> Self {
+        Self {
+            cell: Cell::new(r),
+            _t: PhantomData,
+        }
+    }
+
+    /// Get the (`'static`) reference.
+    #[inline]
+    pub const fn get(&self) -> &'static T {
+        let ptr = self.cell.as_ptr();
+        unsafe { *ptr }
+    }
+}
+
+/// For non-de-duplicated `bool` flags stored in `static` variables. `true`/`false` are the most
+/// commonly de-duplicated values.
+pub type NonDeDuplicatedBool = NonDeDuplicated<bool>;
+
+/// For non-de-duplicated byte arrays stored in `static` variables.
+///
+/// Slice methods can be called directly (through [Deref](core::ops::Deref) to `[u8; N]`, and then
+/// auto-deref to `[u8]`): `MY_STATIC.iter()`, `MY_STATIC.starts_with(b"...")`. In `const` contexts
+/// use [NonDeDuplicated::get] instead.
+impl<const N: usize> NonDeDuplicated<[u8; N]> {
+    /// Construct a new instance with all bytes zero. Handy for reserving scratch regions with
+    /// unique addresses.
+    pub const fn new_zeroed() -> Self {
+        Self::new([0u8; N])
+    }
+
+    /// Whether the bytes equal `other` (byte-by-byte). Unlike `==` on slices this is `const`.
+    pub const fn eq_bytes(&self, other: &[u8]) -> bool {
+        bytes_eq(self.get(), other)
+    }
+
+    /// Construct a new instance from a `'static` slice (like a `const` byte table). Panic (naming
+    /// both lengths) if `s.len()` is not `N`. When used for a `static`, that fails at compile time.
+    ///
+    /// The bytes are still copied: `self` does **not** point to `s`. (The `'static` bound only
+    /// documents the intent, like with [NonDeDuplicatedStr::new_from_static_str].)
+    pub const fn new_from_static_slice(s: &'static [u8]) -> Self {
+        if s.len() != N {
+            panic_len_mismatch(N, b"the slice has", s.len())
+        }
+        Self::new(bytes_to_array(s))
+    }
+
+    /// A reference to the first `M` bytes, as an array. It points into `self`, so (for a `static`)
+    /// it is stable. `M` must not exceed `N`, otherwise it fails at compile time:
+    /// ```rust,compile_fail
+    /// static BYTES: ndd::NonDeDuplicated<[u8; 4]> = ndd::NonDeDuplicated::new(*b"ABCD");
+    /// let _: &[u8; 5] = BYTES.prefix_array();
+    /// ```
+    pub const fn prefix_array<const M: usize>(&self) -> &[u8; M] {
+        const { assert!(M <= N, "M must not exceed N.") };
+        let ptr = self.cell.as_ptr().cast_const().cast::<[u8; M]>();
+        // `[u8; M]` has alignment 1, and `M <= N` bytes are in bounds.
+        unsafe { &*ptr }
+    }
+
+    /// Construct a new instance by concatenating `parts` (in order). Panic if their total length
+    /// is not `N`. When used for a `static`, that fails at compile time.
+    pub const fn new_concat(parts: &[&[u8]]) -> Self {
+        let mut arr = [0u8; N];
+        let mut total = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            total += parts[i].len();
+            i += 1;
+        }
+        if total != N {
+            panic_len_mismatch(N, b"the parts have", total)
+        }
+        let mut pos = 0;
+        i = 0;
+        while i < parts.len() {
+            pos = push_bytes(&mut arr, pos, parts[i]);
+            i += 1;
+        }
+        Self::new(arr)
+    }
+
+    /// Copy the bytes to the start of `dst`. Return the number of bytes copied (`N`). Panic if
+    /// `dst` is shorter than `N`.
+    pub fn copy_into(&self, dst: &mut [u8]) -> usize {
+        copy_bytes_into(self.get(), dst)
+    }
+}
+
+impl<T: Any + Send + Sync> NonDeDuplicated<Option<T>> {
+    /// Get a reference to the value inside the [Option], if any. Like `self.get().as_ref()`, but
+    /// `const`. The reference points into `self`, so (for a `static`) it is stable.
+    pub const fn get_inner(&self) -> Option<&T> {
+        self.get().as_ref()
+    }
+}
+
+/// [NonDeDuplicated]`<[u8; 0]>` has no [Default]: generic code calling `T::default()` would drop
+/// the result, and that panics in debug/miri builds. Use [NonDeDuplicated::new_zeroed] in a
+/// `static` instead.
+///
+/// Zero-sized, so (like any zero-sized values) `static` instances of it are **not** guaranteed to
+/// have distinct addresses.
+impl NonDeDuplicated<[u8; 0]> {
+    /// No bytes. Same as [NonDeDuplicated::new_zeroed].
+    pub const fn empty() -> Self {
+        Self::new([])
+    }
+}
+
+/// So that a `HashMap` (or a `BTreeMap`) with these as keys can be queried by `&[u8]`. Consistent
+/// with (by value) [PartialEq], [Eq], [Ord] and [Hash](core::hash::Hash), since `[u8; N]` hashes
+/// and compares like `[u8]`.
+impl<const N: usize> core::borrow::Borrow<[u8]> for NonDeDuplicated<[u8; N]> {
+    fn borrow(&self) -> &[u8] {
+        self.get()
+    }
+}
+
+/// Same as for [NonDeDuplicated]`<[u8; N]>`, for maps keyed by references to `static` variables
+/// (like `HashMap<&'static NonDeDuplicated<[u8; N]>, V>`).
+impl<const N: usize> core::borrow::Borrow<[u8]> for &NonDeDuplicated<[u8; N]> {
+    fn borrow(&self) -> &[u8] {
+        self.get()
+    }
+}
+
+/// Compare the bytes with `other`: `MY_STATIC == *b"Hello"`.
+impl<const N: usize> PartialEq<[u8; N]> for NonDeDuplicated<[u8; N]> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.get() == other
+    }
+}
+
+/// For non-de-duplicated 16-byte identifiers (like UUID/GUID) stored in `static` variables.
+pub type NonDeDuplicatedBytes16 = NonDeDuplicated<[u8; 16]>;
+
+impl NonDeDuplicatedBytes16 {
+    /// Construct a new instance, storing `x` in **big-endian** byte order (the most significant
+    /// byte first). That is the order in which UUIDs are written (as per RFC 9562).
+    pub const fn new_from_u128(x: u128) -> Self {
+        Self::new(x.to_be_bytes())
+    }
+}
+
+/// `const` equivalent of `a == b`.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Copy `from` to the start of `dst`. Return `from.len()`. Not generic, and `#[inline(never)]`,
+/// for the same reasons as [copy_bytes_to_array].
+#[inline(never)]
+fn copy_bytes_into(from: &[u8], dst: &mut [u8]) -> usize {
+    let Some(dst) = dst.get_mut(..from.len()) else {
+        panic!("The destination is too small.")
+    };
+    dst.copy_from_slice(from);
+    from.len()
+}
+
+/// Append `bytes` to `buf` at `pos`. Return the new `pos`.
+const fn push_bytes(buf: &mut [u8], mut pos: usize, bytes: &[u8]) -> usize {
+    let mut i = 0;
+    while i < bytes.len() {
+        buf[pos] = bytes[i];
+        pos += 1;
+        i += 1;
+    }
+    pos
+}
+
+/// Append decimal digits of `x` to `buf` at `pos`. Return the new `pos`. Only for the detailed
+/// length mismatch messages.
+#[cfg(not(feature = "terse-panics"))]
+const fn push_usize(buf: &mut [u8], pos: usize, mut x: usize) -> usize {
+    // Enough for u64::MAX.
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (x % 10) as u8;
+        x /= 10;
+        if x == 0 {
+            break;
+        }
+    }
+    let (_, digits) = digits.split_at(start);
+    push_bytes(buf, pos, digits)
+}
+
+/// Panic with a message naming both lengths, and whether `N` is too large or too small, like
+/// "N is 5, but the string has 4 byte(s): N is 1 too large." (where `subject` is "the string
+/// has"). `n` and `len` must differ. `const` panics can't format integers (as of mid 2025), so we
+/// format them to a buffer first. `#[inline(never)]`, for the same reasons as
+/// [copy_bytes_to_array].
+///
+/// With `terse-panics` feature, the message is "Length mismatch." instead (see the other
+/// variant of this function).
+#[cfg(not(feature = "terse-panics"))]
+#[inline(never)]
+const fn panic_len_mismatch(n: usize, subject: &[u8], len: usize) -> ! {
+    let mut buf = [0u8; 160];
+    let mut pos = push_bytes(&mut buf, 0, b"N is ");
+    pos = push_usize(&mut buf, pos, n);
+    pos = push_bytes(&mut buf, pos, b", but ");
+    pos = push_bytes(&mut buf, pos, subject);
+    pos = push_bytes(&mut buf, pos, b" ");
+    pos = push_usize(&mut buf, pos, len);
+    pos = push_bytes(&mut buf, pos, b" byte(s): N is ");
+    if n > len {
+        pos = push_usize(&mut buf, pos, n - len);
+        pos = push_bytes(&mut buf, pos, b" too large.");
+    } else {
+        pos = push_usize(&mut buf, pos, len - n);
+        pos = push_bytes(&mut buf, pos, b" too small.");
+    }
+    let (msg, _) = buf.split_at(pos);
+    panic!("{}", str_from_utf8(msg))
+}
+
+/// The only length mismatch panic message with `terse-panics` feature (which saves `.rodata` bytes
+/// on embedded targets). Otherwise the messages name the lengths. With that feature the detailed
+/// messages are not compiled in at all.
+#[cfg(feature = "terse-panics")]
+const TERSE_LEN_MISMATCH: &str = "Length mismatch.";
+
+/// Variant of [panic_len_mismatch] with `terse-panics` feature.
+#[cfg(feature = "terse-panics")]
+#[inline(never)]
+const fn panic_len_mismatch(_n: usize, _subject: &[u8], _len: usize) -> ! {
+    panic!("{}", TERSE_LEN_MISMATCH)
+}
+
+/// Variant of [panic_cstr_len_mismatch] with `terse-panics` feature.
+#[cfg(feature = "terse-panics")]
+#[inline(never)]
+const fn panic_cstr_len_mismatch(_n: usize, _len: usize) -> ! {
+    panic!("{}", TERSE_LEN_MISMATCH)
+}
+
+/// Like [panic_len_mismatch], but for [NonDeDuplicatedCStr::new_from_str], where `n` includes the
+/// trailing NUL byte (so `n` is at least 1).
+#[cfg(not(feature = "terse-panics"))]
+#[inline(never)]
+const fn panic_cstr_len_mismatch(n: usize, len: usize) -> ! {
+    let mut buf = [0u8; 160];
+    let mut pos = push_bytes(&mut buf, 0, b"NonDeDuplicatedCStr with N=");
+    pos = push_usize(&mut buf, pos, n);
+    pos = push_bytes(&mut buf, pos, b" holds ");
+    pos = push_usize(&mut buf, pos, n - 1);
+    pos = push_bytes(
+        &mut buf,
+        pos,
+        b" string byte(s) (plus the trailing NUL byte), but got ",
+    );
+    pos = push_usize(&mut buf, pos, len);
+    pos = push_bytes(&mut buf, pos, b".");
+    let (msg, _) = buf.split_at(pos);
+    panic!("{}", str_from_utf8(msg))
+}
+
+/// Panic (naming the difference, if small) unless `from_len == len`. Part of
+/// [copy_bytes_to_array], not compiled in with `terse-panics` feature.
+#[cfg(not(feature = "terse-panics"))]
+const fn check_copy_len(from_len: usize, len: usize) {
+    if from_len > len {
+        let msg = match from_len - len {
             1 => "Target length is 1 byte too small.",
             2 => "Target length is 2 bytes too small.",
             3 => "Target length is 3 bytes too small.",
@@ -126,8 +650,8 @@ const fn copy_bytes_to_array(to: &mut [u8], from: &[u8], len: usize) {
         };
         panic!("{}", msg)
     }
-    if from.len() < len {
-        let msg = match len - from.len() {
+    if from_len < len {
+        let msg = match len - from_len {
             1 => "Target length is 1 byte too large.",
             2 => "Target length is 2 bytes too large.",
             3 => "Target length is 3 bytes too large.",
@@ -136,6 +660,22 @@ const fn copy_bytes_to_array(to: &mut [u8], from: &[u8], len: usize) {
         };
         panic!("{}", msg)
     }
+}
+
+/// Separate from [bytes_to_array], so that we help monomorphization surface area to be smaller. For
+/// the same reason it's `#[inline(never)]`: There is only one copy of it (and of its panic
+/// messages) in the binary, no matter how many different `N` are used. (It matters only if called
+/// at runtime. When called in `const` context, the result is computed at compile time.)
+///
+/// With `terse-panics` feature, any length mismatch panics with "Length mismatch." instead.
+#[inline(never)]
+const fn copy_bytes_to_array(to: &mut [u8], from: &[u8], len: usize) {
+    #[cfg(feature = "terse-panics")]
+    if from.len() != len {
+        panic!("{}", TERSE_LEN_MISMATCH)
+    }
+    #[cfg(not(feature = "terse-panics"))]
+    check_copy_len(from.len(), len);
     if to.len() != len {
         panic!("Target slice length differs to the specified length.")
     }
@@ -153,262 +693,2320 @@ const fn bytes_to_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
     arr
 }
 
+/// Panic (with a descriptive message) unless `bytes` are a well-formed C string. For constructors
+/// that don't get a `&CStr`, so that any invalid input fails at construction (rather than in
+/// `get()`).
+#[inline(never)]
+const fn assert_valid_cstr(bytes: &[u8]) {
+    let Some((last, content)) = bytes.split_last() else {
+        panic!("A C string needs at least the trailing NUL byte, but there are no bytes.")
+    };
+    if *last != 0 {
+        panic!("The C string must end with a NUL byte.")
+    }
+    let mut i = 0;
+    while i < content.len() {
+        if content[i] == 0 {
+            panic!("The C string must not contain any interior NUL bytes.")
+        }
+        i += 1;
+    }
+}
+
+/// Validate UTF-8. Not generic, and `#[inline(never)]`, for the same reasons as
+/// [copy_bytes_to_array]. Callers guarantee valid UTF-8, so this never panics. (If it does, the
+/// bytes were constructed unsoundly.)
+#[inline(never)]
+const fn str_from_utf8(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("The bytes of a string wrapper are not valid UTF-8."),
+    }
+}
+
+/// Validate a C string. Not generic, and `#[inline(never)]`, for the same reasons as
+/// [copy_bytes_to_array]. Callers guarantee a well-formed C string, so this never panics. (If it
+/// does, the bytes were constructed unsoundly, for example by misusing
+/// [NonDeDuplicatedCStr::new_from_bytes_unchecked].)
+#[inline(never)]
+const fn cstr_from_bytes_with_nul(bytes: &[u8]) -> &CStr {
+    match CStr::from_bytes_with_nul(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("The bytes of a C string wrapper are not a well-formed C string."),
+    }
+}
+
+/// The `N` of [NonDeDuplicatedStr] needed for [NonDeDuplicatedStr::new_from_char]: the length of
+/// `c` in UTF-8 bytes (1 to 4).
+pub const fn char_str_len(c: char) -> usize {
+    c.len_utf8()
+}
+
+/// The `N` of [NonDeDuplicatedStr] needed for [NonDeDuplicatedStr::new_from_chars]: the total
+/// length of `chars` in UTF-8 bytes.
+pub const fn chars_utf8_len(chars: &[char]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        len += chars[i].len_utf8();
+        i += 1;
+    }
+    len
+}
+
+/// The largest `N` accepted by [NonDeDuplicatedStr::new] and [NonDeDuplicatedCStr::new_from_str]
+/// (1 MiB), checked at compile time. A larger `N` is more likely a typo than intended (and it would
+/// build a huge array during `const` evaluation). For larger strings use
+/// [NonDeDuplicatedStr::new_with_max_n] or [NonDeDuplicatedCStr::new_from_str_with_max_n].
+/// ```rust
+/// const HUGE: &str = match str::from_utf8(&[b'x'; ndd::MAX_N + 1]) {
+///     Ok(s) => s,
+///     Err(_) => unreachable!(),
+/// };
+/// static OK: ndd::NonDeDuplicatedStr<{ ndd::MAX_N + 1 }> =
+///     ndd::NonDeDuplicatedStr::new_with_max_n::<{ ndd::MAX_N + 2 }>(HUGE);
+/// assert_eq!(OK.get().len(), ndd::MAX_N + 1);
+/// ```
+/// Only the cap rejects this one (the length of `HUGE` matches `N`):
+/// ```rust,compile_fail
+/// # const HUGE: &str = match str::from_utf8(&[b'x'; ndd::MAX_N + 1]) {
+/// #     Ok(s) => s,
+/// #     Err(_) => unreachable!(),
+/// # };
+/// static TOO_LARGE: ndd::NonDeDuplicatedStr<{ ndd::MAX_N + 1 }> =
+///     ndd::NonDeDuplicatedStr::new(HUGE);
+/// ```
+pub const MAX_N: usize = 1 << 20;
+
 /// For non-de-duplicated string slices stored in `static` variables.
+///
+/// Invalid UTF-8 bytes fail at compile time:
+/// ```rust,compile_fail
+/// static BAD: ndd::NonDeDuplicatedStr<2> = ndd::NonDeDuplicatedStr::new_from_array([b'A', 0xC3]);
+/// ```
 pub type NonDeDuplicatedStr<const N: usize> = NonDeDuplicatedFlexible<[u8; N], str>;
 impl<const N: usize> NonDeDuplicatedStr<N> {
-    /// Construct a new instance.
+    /// Construct a new instance. `N` must not exceed [MAX_N] (checked at compile time).
     pub const fn new(s: &str) -> Self {
+        Self::new_with_max_n::<MAX_N>(s)
+    }
+
+    /// Like [NonDeDuplicatedStr::new], but with `MAX` (instead of [MAX_N]) as the largest accepted
+    /// `N`. An escape hatch for large strings:
+    /// ```rust
+    /// static TWO: ndd::NonDeDuplicatedStr<2> = ndd::NonDeDuplicatedStr::new_with_max_n::<2>("Hi");
+    /// ```
+    /// ```rust,compile_fail
+    /// static TWO: ndd::NonDeDuplicatedStr<2> = ndd::NonDeDuplicatedStr::new_with_max_n::<1>("Hi");
+    /// ```
+    pub const fn new_with_max_n<const MAX: usize>(s: &str) -> Self {
+        const {
+            assert!(
+                N <= MAX,
+                "N of NonDeDuplicatedStr is too large. If intended, use new_with_max_n."
+            )
+        };
         Self {
             cell: Cell::new(bytes_to_array(s.as_bytes())),
             _t: PhantomData,
         }
     }
 
-    /// Get a reference.
-    ///
-    /// Implementation details: Since this type, and this function, is intended to be used for
-    /// `static` variables only, speed doesn't matter here. So, we use [core::str::from_utf8]
-    /// (instead of [core::str::from_utf8_unchecked]).
-    pub const fn get(&self) -> &str {
-        let ptr = self.cell.as_ptr();
-        let bytes = unsafe { &*ptr };
-        match core::str::from_utf8(bytes) {
-            Ok(s) => s,
-            Err(_) => unreachable!(),
+    /// Construct a new instance from (UTF-8) bytes. Panic if `arr` is not valid UTF-8. When used
+    /// for a `static`, that fails at compile time.
+    pub const fn new_from_array(arr: [u8; N]) -> Self {
+        if core::str::from_utf8(&arr).is_err() {
+            panic!("The bytes must be valid UTF-8.")
         }
-    }
-}
-
-/// For non-de-duplicated string slices stored in `static` variables.
-pub type NonDeDuplicatedCStr<const N: usize> = NonDeDuplicatedFlexible<[u8; N], CStr>;
-impl<const N: usize> NonDeDuplicatedCStr<N> {
-    /// Construct a new instance.
-    pub const fn new(s: &CStr) -> Self {
         Self {
-            cell: Cell::new(bytes_to_array(s.to_bytes())),
+            cell: Cell::new(arr),
             _t: PhantomData,
         }
     }
 
-    /// The given `arr` must be a well-formed C string, that is,
-    /// - **not** containing any internal NUL bytes, and
-    /// - end with a NUL byte, like `b"abc\0"`.
-    pub const fn new_from_bytes(arr: [u8; N]) -> Self {
-        // Validate early, rather than waiting for validation by .get()
-        let _ = core::hint::black_box(CStr::from_bytes_with_nul(&arr));
-        Self {
-            cell: Cell::new(arr),
-            _t: PhantomData,
+    /// Like [NonDeDuplicatedStr::new], but on a length mismatch the panic message names both `N`
+    /// and `s.len()`, and whether `N` is too large or too small, like "N is 5, but the string has 4
+    /// byte(s): N is 1 too large." When used for a `static`, that is a compile time error.
+    pub const fn new_checked(s: &str) -> Self {
+        if !Self::fits(s) {
+            panic_len_mismatch(N, b"the string has", s.len())
         }
+        Self::new(s)
     }
 
-    ///  The `given &`[str] must, like C string, not contain any internal NUL bytes. However, do
-    ///  **not** include the trailing NUL byte - that is added automatically.
-    pub const fn new_from_str(s: &str) -> Self {
+    /// Whether [NonDeDuplicatedStr::new] accepts `s` (rather than panicking), that is, whether
+    /// `s.len()` (in bytes) is `N`.
+    pub const fn fits(s: &str) -> bool {
+        s.len() == N
+    }
+
+    /// Construct a new instance holding a single `char`, UTF-8 encoded. `N` must be
+    /// [char_str_len]`(c)`.
+    pub const fn new_from_char(c: char) -> Self {
+        Self::new(c.encode_utf8(&mut [0u8; 4]))
+    }
+
+    /// Construct a new instance holding `chars`, UTF-8 encoded. Panic (naming both lengths) if `N`
+    /// is not [chars_utf8_len]`(chars)`. When used for a `static`, that fails at compile time.
+    pub const fn new_from_chars(chars: &[char]) -> Self {
+        let len = chars_utf8_len(chars);
+        if len != N {
+            panic_len_mismatch(N, b"the chars have", len)
+        }
         let mut arr = [0u8; N];
-        if let Some((_, sub_slice)) = arr.split_last_mut() {
-            crate::copy_bytes_to_array(sub_slice, s.as_bytes(), s.len());
-        } else {
-            unreachable!()
+        let mut pos = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            pos = push_bytes(
+                &mut arr,
+                pos,
+                chars[i].encode_utf8(&mut [0u8; 4]).as_bytes(),
+            );
+            i += 1;
         }
-        Self::new_from_bytes(arr)
+        Self::new(str_from_utf8(&arr))
+    }
+
+    /// Same as [NonDeDuplicatedStr::new], but accepting only a `'static` source (like a string
+    /// literal). Use it where the source is meant to be a literal, so that the signature says so.
+    ///
+    /// The bytes are still copied: `self` does **not** point to `s`.
+    pub const fn new_from_static_str(s: &'static str) -> Self {
+        Self::new(s)
     }
 
     /// Get a reference.
     ///
     /// Implementation details: Since this type, and this function, is intended to be used for
-    /// `static` variables only, speed doesn't matter here. So, we use [CStr::from_bytes_with_nul]
-    /// (instead of [CStr::from_bytes_with_nul_unchecked]).
-    pub const fn get(&self) -> &CStr {
+    /// `static` variables only, speed doesn't matter here. So, we use [core::str::from_utf8]
+    /// (instead of [core::str::from_utf8_unchecked]).
+    #[inline]
+    pub const fn get(&self) -> &str {
         let ptr = self.cell.as_ptr();
         let bytes = unsafe { &*ptr };
-        match CStr::from_bytes_with_nul(bytes) {
-            Ok(s) => s,
-            Err(_) => unreachable!(),
-        }
+        str_from_utf8(bytes)
     }
-}
 
-/// For now, [Sync] (and [NonDeDuplicatedFlexible] in general) requires that `OWN` is both [Sync]
-/// AND [Send], following
-/// [std::sync::Mutex](https://doc.rust-lang.org/nightly/std/sync/struct.Mutex.html#impl-Sync-for-Mutex%3CT%3E).
-/// However, from <https://doc.rust-lang.org/nightly/core/marker/trait.Sync.html> it seems that `T:
-/// Send` may be unnecessary? Please advise.
-///
-/// Either way, [NonDeDuplicated], [NonDeDuplicatedStr] and [NonDeDuplicatedCStr] (and underlying
-/// [NonDeDuplicatedFlexible]) exist specifically for static variables. Those get never moved out.
-/// So, (unlike [std::sync::Mutex]) they do **not** need to implement [Send].
+    /// The address and the string, together, like [NonDeDuplicated::addr_and_ref].
+    pub fn addr_and_ref(&self) -> (usize, &str) {
+        (self.as_erased_ptr() as usize, self.get())
+    }
+
+    /// Same as [NonDeDuplicatedStr::get].
+    ///
+    /// Usable in initializers of other `static` variables:
+    /// ```rust
+    /// static GREETING: ndd::NonDeDuplicatedStr<5> = ndd::NonDeDuplicatedStr::new("Hello");
+    /// static GREETING_LEN: usize = GREETING.as_str().len();
+    /// assert_eq!(GREETING_LEN, 5);
+    /// ```
+    /// But not in `const` items: they can't read from `static` variables that have interior
+    /// mutability, like `ndd` types. (For the length alone, use `N` instead.)
+    /// ```rust,compile_fail
+    /// static GREETING: ndd::NonDeDuplicatedStr<5> = ndd::NonDeDuplicatedStr::new("Hello");
+    /// const GREETING_LEN: usize = GREETING.as_str().len();
+    /// ```
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        self.get()
+    }
+
+    /// Like [NonDeDuplicatedStr::get], but without validating UTF-8 (with
+    /// [core::str::from_utf8_unchecked]). Prefer [NonDeDuplicatedStr::get], unless profiling shows
+    /// that the validation matters.
+    ///
+    /// # Safety
+    ///
+    /// None beyond constructing `self` through a safe constructor: those guarantee valid UTF-8.
+    /// This is `unsafe` only to mark the skipped check.
+    #[inline]
+    pub const unsafe fn get_unchecked(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(self.as_array()) }
+    }
+
+    /// Same as `self.get().lines()`.
+    pub fn lines(&self) -> core::str::Lines<'_> {
+        self.get().lines()
+    }
+
+    /// Same as `self.get().split(sep)`.
+    pub fn split(&self, sep: char) -> core::str::Split<'_, char> {
+        self.get().split(sep)
+    }
+
+    /// Same as `self.get().bytes()`.
+    pub fn bytes(&self) -> core::str::Bytes<'_> {
+        self.get().bytes()
+    }
+
+    /// Same as `self.get().char_indices()`.
+    pub fn char_indices(&self) -> core::str::CharIndices<'_> {
+        self.get().char_indices()
+    }
+
+    /// Get a sub-slice for byte range `start..end`, like `&self.get()[start..end]`, but `const`.
+    /// It points into `self`, so (for a `static`) its address is stable and unique, too.
+    ///
+    /// Panic if the range is out of bounds, or if `start` or `end` is not on a `char` boundary.
+    pub const fn substr(&self, start: usize, end: usize) -> &str {
+        if start > end || end > N {
+            panic!("The byte range is out of bounds.")
+        }
+        let s = self.get();
+        if !s.is_char_boundary(start) || !s.is_char_boundary(end) {
+            panic!("The byte range must start and end on char boundaries.")
+        }
+        let (before_end, _) = self.as_array().split_at(end);
+        let (_, range) = before_end.split_at(start);
+        str_from_utf8(range)
+    }
+
+    /// Get a reference to the inner (UTF-8) byte array.
+    pub const fn as_array(&self) -> &[u8; N] {
+        let ptr = self.cell.as_ptr();
+        unsafe { &*ptr }
+    }
+
+    /// Whether the (UTF-8) bytes equal `other` (byte-by-byte). Unlike `==` on slices this is
+    /// `const`.
+    pub const fn eq_bytes(&self, other: &[u8]) -> bool {
+        bytes_eq(self.as_array(), other)
+    }
+
+    /// Copy the (UTF-8) bytes to the start of `dst`. Return the number of bytes copied (`N`).
+    /// Panic if `dst` is shorter than `N`.
+    pub fn copy_into(&self, dst: &mut [u8]) -> usize {
+        copy_bytes_into(self.as_array(), dst)
+    }
+
+    /// Whether the string equals `other`. Unlike `==` on [str] this is `const`.
+    ///
+    /// Like [NonDeDuplicatedStr::as_str], usable in initializers of other `static` variables, but
+    /// not in `const` items.
+    pub const fn eq_const_str(&self, other: &str) -> bool {
+        self.eq_bytes(other.as_bytes())
+    }
+
+    /// Same as `self.get().eq_ignore_ascii_case(other)`. For case-insensitive tokens.
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.get().eq_ignore_ascii_case(other)
+    }
+
+    /// Whether all bytes are ASCII.
+    ///
+    /// Usable in initializers of other `static` variables. (Not in `const` ones: they can't read
+    /// from `static` variables that have interior mutability, like `ndd` types.)
+    pub const fn is_ascii(&self) -> bool {
+        self.as_array().is_ascii()
+    }
+
+    /// Whether no byte is NUL (zero), so that the string can become a C string (once a trailing
+    /// NUL byte is appended), like with [NonDeDuplicatedCStr::new_from_str].
+    ///
+    /// Usable in initializers of other `static` variables, so it can be asserted at compile time:
+    /// ```rust
+    /// static NAME: ndd::NonDeDuplicatedStr<4> = ndd::NonDeDuplicatedStr::new("Ciao");
+    /// static _NAME_NUL_FREE: () = assert!(NAME.has_no_interior_nul());
+    /// ```
+    pub const fn has_no_interior_nul(&self) -> bool {
+        let bytes = self.as_array();
+        let mut i = 0;
+        while i < N {
+            if bytes[i] == 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// [NonDeDuplicatedStr]`<0>` has no [Default], for the same reason as
+/// [NonDeDuplicated]`<[u8; 0]>` (and it's zero-sized, too).
+impl NonDeDuplicatedStr<0> {
+    /// The empty string. For `static` initializers: `static NONE: NonDeDuplicatedStr<0> =
+    /// NonDeDuplicatedStr::empty();`.
+    pub const fn empty() -> Self {
+        Self::new("")
+    }
+}
+
+/// The UTF-8 bytes of the string.
+impl<const N: usize> AsRef<[u8]> for NonDeDuplicatedStr<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.get().as_bytes()
+    }
+}
+
+/// Iterate over the chars: `for c in &MY_STATIC { ... }`. For the UTF-8 bytes use
+/// [NonDeDuplicatedStr::bytes].
+impl<'a, const N: usize> IntoIterator for &'a NonDeDuplicatedStr<N> {
+    type Item = char;
+    type IntoIter = core::str::Chars<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.get().chars()
+    }
+}
+
+/// Compare by value (and not by address), even if `N` and `M` differ. Same as
+/// [NonDeDuplicatedFlexible::val_eq], but not limited to `N == M`. For comparison by address use
+/// [NonDeDuplicatedFlexible::ref_eq].
+impl<const N: usize, const M: usize> PartialEq<NonDeDuplicatedStr<M>> for NonDeDuplicatedStr<N> {
+    fn eq(&self, other: &NonDeDuplicatedStr<M>) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<const N: usize> Eq for NonDeDuplicatedStr<N> {}
+
+/// Hash by value, consistent with [PartialEq], like [str]. To hash by address use [AddrHashed]
+/// instead.
+impl<const N: usize> core::hash::Hash for NonDeDuplicatedStr<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+/// Compare the (UTF-8) bytes with `other`: `MY_STATIC == *b"Hello"`.
+impl<const N: usize> PartialEq<[u8; N]> for NonDeDuplicatedStr<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.as_array() == other
+    }
+}
+
+/// Keeps the `'static` lifetime: `let s: &'static str = (&MY_STATIC).into();`.
+impl<const N: usize> From<&'static NonDeDuplicatedStr<N>> for &'static str {
+    fn from(ndd: &'static NonDeDuplicatedStr<N>) -> Self {
+        ndd.get()
+    }
+}
+
+/// Inherent methods rather than `AsRef<OsStr>` and `AsRef<Path>` impls, because those would make
+/// `.as_ref()` ambiguous (for existing callers of `AsRef<[u8]>` and `AsRef<str>`). Requires `std`
+/// feature.
+#[cfg(feature = "std")]
+impl<const N: usize> NonDeDuplicatedStr<N> {
+    /// For `std` APIs that take `impl AsRef<OsStr>`.
+    pub fn as_os_str(&self) -> &std::ffi::OsStr {
+        std::ffi::OsStr::new(self.get())
+    }
+
+    /// For `std::fs` (and other) APIs that take `impl AsRef<Path>`.
+    pub fn as_path(&self) -> &std::path::Path {
+        std::path::Path::new(self.get())
+    }
+}
+
+/// Marker type (never instantiated) for [NonDeDuplicatedAscii].
+pub enum AsciiStr {}
+
+/// For non-de-duplicated ASCII-only string slices stored in `static` variables. Like
+/// [NonDeDuplicatedStr], but construction rejects any non-ASCII bytes. When used for a `static`,
+/// that fails at compile time:
+/// ```rust,compile_fail
+/// static TOKEN: ndd::NonDeDuplicatedAscii<5> = ndd::NonDeDuplicatedAscii::new("café");
+/// ```
+pub type NonDeDuplicatedAscii<const N: usize> = NonDeDuplicatedFlexible<[u8; N], AsciiStr>;
+impl<const N: usize> NonDeDuplicatedAscii<N> {
+    /// Construct a new instance. Panic if `s` contains any non-ASCII bytes.
+    pub const fn new(s: &str) -> Self {
+        if !s.is_ascii() {
+            panic!("The string must contain ASCII bytes only.")
+        }
+        Self {
+            cell: Cell::new(bytes_to_array(s.as_bytes())),
+            _t: PhantomData,
+        }
+    }
+
+    /// Get a reference.
+    #[inline]
+    pub const fn get(&self) -> &str {
+        let ptr = self.cell.as_ptr();
+        let bytes = unsafe { &*ptr };
+        str_from_utf8(bytes)
+    }
+}
+
+/// Copy `s` to heap, and leak it, so that the result has a unique `'static` address - for strings
+/// computed at runtime (for example, at startup). Every call allocates (and leaks), even for equal
+/// strings. For compile-time strings use [NonDeDuplicatedStr] instead.
 ///
-/// Also, unclear if `TO` needs to be [Send] and [Sync].
-unsafe impl<OWN: Any + Send + Sync, TO: Any + Send + Sync + ?Sized> Sync
-    for NonDeDuplicatedFlexible<OWN, TO>
-{
+/// This returns `&'static str` (rather than a reference to [NonDeDuplicatedStr]), because the
+/// length of `s` is not known at compile time. Even an empty result has a unique address (at least
+/// one byte gets allocated).
+///
+/// Requires `alloc` feature.
+#[cfg(feature = "alloc")]
+pub fn intern_str(s: &str) -> &'static str {
+    let mut bytes = alloc::vec::Vec::with_capacity(s.len().max(1));
+    bytes.extend_from_slice(s.as_bytes());
+    str_from_utf8(bytes.leak())
+}
+
+/// A mutable builder of a string with capacity `CAP` bytes, written to with [core::fmt::Write]
+/// (for example, with [core::write!]). Then [NonDeDuplicatedStrBuf::freeze] it into a
+/// [NonDeDuplicatedStr].
+///
+/// This is **not** a non-de-duplicated type itself, and it is **not** for `static` variables. It
+/// can be used for locals (and it can be dropped).
+///
+/// There is a tension with `ndd`'s static-only design: [core::fmt::Write] is not `const`, so the
+/// frozen [NonDeDuplicatedStr] is built at runtime and it can't be assigned to a `static`. Because
+/// its [Drop::drop] panics in debug/miri builds, it has to be leaked (for example, with
+/// `Box::leak`), so that it has `'static` lifetime.
+pub struct NonDeDuplicatedStrBuf<const CAP: usize> {
+    buf: [u8; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> NonDeDuplicatedStrBuf<CAP> {
+    /// Construct an empty instance.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0u8; CAP],
+            len: 0,
+        }
+    }
+
+    /// Construct an instance holding `s`, with the rest of the buffer filled with `fill` (for
+    /// fixed-width records, for example padded with spaces). Panic if `s` doesn't fit in `CAP`.
+    ///
+    /// The padding is not part of the content: [NonDeDuplicatedStrBuf::as_str] and
+    /// [NonDeDuplicatedStrBuf::as_bytes] return `s` (and anything written later) only. Only
+    /// [NonDeDuplicatedStrBuf::as_bytes_padded] includes the padding (so `fill` doesn't need to be
+    /// valid UTF-8). Later writes overwrite the padding.
+    pub const fn new_padded_with(s: &str, fill: u8) -> Self {
+        if s.len() > CAP {
+            panic!("The string doesn't fit in the capacity.")
+        }
+        let mut buf = [fill; CAP];
+        let len = push_bytes(&mut buf, 0, s.as_bytes());
+        Self { buf, len }
+    }
+
+    /// Construct an instance holding `s`, or (if `s` doesn't fit in `CAP`) as much of `s` as fits,
+    /// cut at a `char` boundary (so the result stays valid UTF-8). For fixed-size name fields
+    /// where over-long inputs should be clipped (rather than rejected). The rest of the buffer is
+    /// zero.
+    pub const fn new_truncating(s: &str) -> Self {
+        let mut len = if s.len() < CAP { s.len() } else { CAP };
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0u8; CAP];
+        let len = push_bytes(&mut buf, 0, s.as_bytes().split_at(len).0);
+        Self { buf, len }
+    }
+
+    /// Get the string written so far.
+    pub const fn as_str(&self) -> &str {
+        str_from_utf8(self.buf.split_at(self.len).0)
+    }
+
+    /// How many more bytes fit (before the capacity `CAP` is reached).
+    pub const fn remaining(&self) -> usize {
+        CAP - self.len
+    }
+
+    /// Get the (UTF-8) bytes written so far (without the padding).
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+
+    /// Get the whole buffer, that is, the bytes written so far, followed by padding up to `CAP`.
+    /// The padding is zero bytes, unless constructed with [NonDeDuplicatedStrBuf::new_padded_with].
+    pub const fn as_bytes_padded(&self) -> &[u8; CAP] {
+        &self.buf
+    }
+
+    /// Copy the string written so far into a new [NonDeDuplicatedStr]. Its length must be exactly
+    /// `N`.
+    pub const fn freeze<const N: usize>(self) -> NonDeDuplicatedStr<N> {
+        NonDeDuplicatedStr::new(self.as_str())
+    }
+}
+
+impl<const CAP: usize> Default for NonDeDuplicatedStrBuf<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writing fails with [core::fmt::Error] if the result would not fit in `CAP` bytes. Then nothing
+/// from that failing [core::fmt::Write::write_str] call is kept. But `write!` calls it once per
+/// piece, so any earlier pieces of the same `write!` stay in the buffer.
+impl<const CAP: usize> core::fmt::Write for NonDeDuplicatedStrBuf<CAP> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > CAP {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// The `N` of [NonDeDuplicatedCStr] that holds the same string as [NonDeDuplicatedStr]`<N>`, that
+/// is, `N + 1` (for the trailing NUL byte). For keeping paired `static` variables consistent:
+/// ```rust
+/// use ndd::{NonDeDuplicatedCStr, NonDeDuplicatedStr};
+///
+/// const LEN: usize = 4;
+/// static NAME: NonDeDuplicatedStr<LEN> = NonDeDuplicatedStr::new("ndd!");
+/// static NAME_C: NonDeDuplicatedCStr<{ ndd::cstr_capacity_for_str::<LEN>() }> =
+///     NonDeDuplicatedCStr::new_from_str("ndd!");
+/// ```
+/// (Not usable with a generic `N`, because `stable` Rust doesn't allow generic const expressions.)
+pub const fn cstr_capacity_for_str<const N: usize>() -> usize {
+    N + 1
+}
+
+/// The `N` of [NonDeDuplicatedCStr] needed for `bytes`: `bytes.len()` if `bytes` already ends with
+/// a NUL byte, `bytes.len() + 1` otherwise. (This does not check for interior NUL bytes.)
+/// ```rust
+/// const LEN: usize = ndd::cstr_array_len_for_bytes(b"abc");
+/// static ABC: ndd::NonDeDuplicatedCStr<LEN> = ndd::NonDeDuplicatedCStr::new_from_str("abc");
+/// assert_eq!(ABC.get(), c"abc");
+/// ```
+pub const fn cstr_array_len_for_bytes(bytes: &[u8]) -> usize {
+    match bytes.last() {
+        Some(0) => bytes.len(),
+        _ => bytes.len() + 1,
+    }
+}
+
+/// For non-de-duplicated C string slices stored in `static` variables.
+///
+/// For FFI: the pointer of each such `static` (`MY_STATIC.get().as_ptr()`) is stable, and unique -
+/// even if another `static` (or a `const`, or a literal) holds the same C string. So C code may
+/// compare or store these pointers as identities.
+///
+/// `N` includes the trailing NUL byte, so it must be at least 1. Otherwise construction fails at
+/// compile time, even with a constructor that doesn't validate the bytes:
+/// ```rust,compile_fail
+/// static EMPTY: ndd::NonDeDuplicatedCStr<0> =
+///     unsafe { ndd::NonDeDuplicatedCStr::new_from_bytes_unchecked([]) };
+/// ```
+pub type NonDeDuplicatedCStr<const N: usize> = NonDeDuplicatedFlexible<[u8; N], CStr>;
+impl<const N: usize> NonDeDuplicatedCStr<N> {
+    /// Evaluated (at compile time) by every constructor, so that a zero `N` is rejected even if
+    /// the construction itself is not evaluated at compile time.
+    const N_NOT_ZERO: () = assert!(
+        N > 0,
+        "N of NonDeDuplicatedCStr must be at least 1 (for the trailing NUL byte)."
+    );
+
+    /// Construct a new instance. `N` includes the trailing NUL byte, so it is one more than
+    /// `s.count_bytes()`.
+    pub const fn new(s: &CStr) -> Self {
+        let () = Self::N_NOT_ZERO;
+        Self {
+            cell: Cell::new(bytes_to_array(s.to_bytes_with_nul())),
+            _t: PhantomData,
+        }
+    }
+
+    /// The given `arr` must be a well-formed C string, that is,
+    /// - **not** containing any internal NUL bytes, and
+    /// - end with a NUL byte, like `b"abc\0"`.
+    pub const fn new_from_bytes(arr: [u8; N]) -> Self {
+        let () = Self::N_NOT_ZERO;
+        // Validate early, rather than waiting for validation by .get()
+        assert_valid_cstr(&arr);
+        Self {
+            cell: Cell::new(arr),
+            _t: PhantomData,
+        }
+    }
+
+    /// Like [NonDeDuplicatedCStr::new_from_bytes], but from a slice. Its length must be exactly
+    /// `N` (including the trailing NUL byte).
+    pub const fn new_from_byte_slice(bytes: &[u8]) -> Self {
+        Self::new_from_bytes(bytes_to_array(bytes))
+    }
+
+    /// Like [NonDeDuplicatedCStr::new_from_bytes], but without validating `arr`. Prefer
+    /// [NonDeDuplicatedCStr::new_from_bytes] - its validation runs at compile time for `static`
+    /// variables anyway. This parallels [CStr::from_bytes_with_nul_unchecked].
+    ///
+    /// # Safety
+    ///
+    /// `arr` must be a well-formed C string, that is,
+    /// - **not** containing any internal NUL bytes, and
+    /// - end with a NUL byte.
+    pub const unsafe fn new_from_bytes_unchecked(arr: [u8; N]) -> Self {
+        let () = Self::N_NOT_ZERO;
+        Self {
+            cell: Cell::new(arr),
+            _t: PhantomData,
+        }
+    }
+
+    ///  The `given &`[str] must, like C string, not contain any internal NUL bytes. However, do
+    ///  **not** include the trailing NUL byte - that is added automatically. So `N` is `s.len() +
+    ///  1`, where `s.len()` is in bytes (not in characters).
+    ///
+    ///  `N` must not exceed [MAX_N] (checked at compile time).
+    pub const fn new_from_str(s: &str) -> Self {
+        Self::new_from_str_with_max_n::<MAX_N>(s)
+    }
+
+    /// Like [NonDeDuplicatedCStr::new_from_str], but with `MAX` (instead of [MAX_N]) as the
+    /// largest accepted `N`, like [NonDeDuplicatedStr::new_with_max_n].
+    pub const fn new_from_str_with_max_n<const MAX: usize>(s: &str) -> Self {
+        const {
+            assert!(
+                N <= MAX,
+                "N of NonDeDuplicatedCStr is too large. If intended, use new_from_str_with_max_n."
+            )
+        };
+        let () = Self::N_NOT_ZERO;
+        // Check here (rather than leaving it to copy_bytes_to_array), so that the message is in
+        // terms of N (including the trailing NUL byte).
+        if !Self::fits(s) {
+            panic_cstr_len_mismatch(N, s.len())
+        }
+        let mut arr = [0u8; N];
+        if let Some((_, sub_slice)) = arr.split_last_mut() {
+            // The string fills all but the last byte (which stays NUL).
+            crate::copy_bytes_to_array(sub_slice, s.as_bytes(), s.len());
+        } else {
+            unreachable!()
+        }
+        Self::new_from_bytes(arr)
+    }
+
+    /// Whether `s` has the right length for [NonDeDuplicatedCStr::new_from_str], that is, whether
+    /// `s.len() + 1` (in bytes) is `N`. (This does not check for interior NUL bytes.)
+    pub const fn fits(s: &str) -> bool {
+        s.len() + 1 == N
+    }
+
+    /// Get a reference.
+    ///
+    /// Implementation details: Since this type, and this function, is intended to be used for
+    /// `static` variables only, speed doesn't matter here. So, we use [CStr::from_bytes_with_nul]
+    /// (instead of [CStr::from_bytes_with_nul_unchecked]).
+    #[inline]
+    pub const fn get(&self) -> &CStr {
+        let ptr = self.cell.as_ptr();
+        let bytes = unsafe { &*ptr };
+        cstr_from_bytes_with_nul(bytes)
+    }
+
+    /// Like [NonDeDuplicatedCStr::get], but without validation (with
+    /// [CStr::from_bytes_with_nul_unchecked]). Prefer [NonDeDuplicatedCStr::get], unless profiling
+    /// shows that the validation matters.
+    ///
+    /// # Safety
+    ///
+    /// `self` must hold a well-formed C string. That is guaranteed, unless `self` was constructed
+    /// with [NonDeDuplicatedCStr::new_from_bytes_unchecked] from invalid bytes.
+    #[inline]
+    pub const unsafe fn get_unchecked(&self) -> &CStr {
+        unsafe { CStr::from_bytes_with_nul_unchecked(self.as_array()) }
+    }
+
+    /// The address and the C string, together, like [NonDeDuplicated::addr_and_ref].
+    pub fn addr_and_ref(&self) -> (usize, &CStr) {
+        (self.as_erased_ptr() as usize, self.get())
+    }
+
+    /// Get a reference to the inner byte array, including the trailing NUL byte.
+    pub const fn as_array(&self) -> &[u8; N] {
+        let ptr = self.cell.as_ptr();
+        unsafe { &*ptr }
+    }
+
+    /// The string bytes (without the trailing NUL byte), and the index of the trailing NUL byte
+    /// (in [NonDeDuplicatedCStr::as_array]). For FFI code that tracks that position separately.
+    ///
+    /// The index is always `N - 1`, since a well-formed C string has no interior NUL bytes.
+    pub const fn content_and_nul_index(&self) -> (&[u8], usize) {
+        let content = self.get().to_bytes();
+        (content, content.len())
+    }
+
+    /// Whether all bytes are ASCII. (The trailing NUL byte is ASCII, too.)
+    pub const fn is_ascii(&self) -> bool {
+        self.as_array().is_ascii()
+    }
+
+    /// An owned copy of the string (without the trailing NUL byte), with any invalid UTF-8
+    /// sequences replaced by [char::REPLACEMENT_CHARACTER], as with `CStr::to_string_lossy`. For
+    /// diagnostics.
+    ///
+    /// Requires `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub fn to_string_lossy(&self) -> alloc::string::String {
+        alloc::string::String::from_utf8_lossy(self.get().to_bytes()).into_owned()
+    }
+}
+
+/// The bytes of the C string **without** the trailing NUL byte, like [CStr::to_bytes].
+impl<const N: usize> AsRef<[u8]> for NonDeDuplicatedCStr<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.get().to_bytes()
+    }
+}
+
+/// Keeps the `'static` lifetime: `let c: &'static CStr = (&MY_STATIC).into();`.
+impl<const N: usize> From<&'static NonDeDuplicatedCStr<N>> for &'static CStr {
+    fn from(ndd: &'static NonDeDuplicatedCStr<N>) -> Self {
+        ndd.get()
+    }
+}
+
+/// For `impl AsRef<CStr>` parameters, like those of FFI helpers.
+impl<const N: usize> AsRef<CStr> for NonDeDuplicatedCStr<N> {
+    fn as_ref(&self) -> &CStr {
+        self.get()
+    }
+}
+
+/// Why [NonDeDuplicatedCStr::try_from] rejected the given bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CStrBytesError {
+    /// The number of bytes differs from `N`.
+    WrongLength { expected: usize, actual: usize },
+    /// The bytes are not a well-formed C string.
+    Malformed(core::ffi::FromBytesWithNulError),
+}
+
+impl core::fmt::Display for CStrBytesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongLength { expected, actual } => write!(
+                f,
+                "Expected {} byte(s) (including the trailing NUL byte), but got {}.",
+                expected, actual
+            ),
+            Self::Malformed(e) => write!(f, "Not a well-formed C string: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for CStrBytesError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::WrongLength { .. } => None,
+            Self::Malformed(e) => Some(e),
+        }
+    }
+}
+
+/// The runtime counterpart of [NonDeDuplicatedCStr::new_from_bytes]: `bytes` must have exactly `N`
+/// bytes, and be a well-formed C string (including the trailing NUL byte).
+///
+/// The result is built at runtime, so it can't be assigned to a `static`. Like with
+/// [NonDeDuplicatedStrBuf::freeze], leak it (for example, with `Box::leak`), so that it has
+/// `'static` lifetime.
+impl<const N: usize> TryFrom<&[u8]> for NonDeDuplicatedCStr<N> {
+    type Error = CStrBytesError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, CStrBytesError> {
+        let Ok(arr) = <[u8; N]>::try_from(bytes) else {
+            return Err(CStrBytesError::WrongLength {
+                expected: N,
+                actual: bytes.len(),
+            });
+        };
+        CStr::from_bytes_with_nul(&arr).map_err(CStrBytesError::Malformed)?;
+        Ok(Self::new_from_bytes(arr))
+    }
+}
+
+/// Why converting a [NonDeDuplicatedCStr] to a [NonDeDuplicatedStr] failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CStrToStrError {
+    /// The C string's length (without the trailing NUL byte) differs from `M`.
+    WrongLength { expected: usize, actual: usize },
+    /// The C string is not valid UTF-8.
+    NotUtf8(core::str::Utf8Error),
+}
+
+impl core::fmt::Display for CStrToStrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongLength { expected, actual } => write!(
+                f,
+                "Expected a C string of {} byte(s) (without the trailing NUL byte), but got {}.",
+                expected, actual
+            ),
+            Self::NotUtf8(e) => write!(f, "Not valid UTF-8: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for CStrToStrError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::WrongLength { .. } => None,
+            Self::NotUtf8(e) => Some(e),
+        }
+    }
 }
 
-/// [NonDeDuplicated] and friends are intended for `static` (immutable) variables only. So
-/// [Drop::drop] panics in debug/miri builds.
-impl<OWN: Any + Send + Sync, TO: Any + Send + Sync + ?Sized> Drop
-    for NonDeDuplicatedFlexible<OWN, TO>
-{
-    fn drop(&mut self) {
-        // If the client uses Box::leak() or friends, then drop() will NOT happen. That is OK: A
-        // leaked reference will have static lifetime.
-        #[cfg(any(debug_assertions, miri))]
-        panic!("Do not use for local variables, const, or on heap. Use for static variables only.")
+/// Convert a C string to a string (without the trailing NUL byte), validating UTF-8. `M` must be
+/// `N - 1`.
+///
+/// `cstr` is consumed (and not dropped) either way. Like with [TryFrom]`<&[u8]>` for
+/// [NonDeDuplicatedCStr], the result is built at runtime, so leak it.
+impl<const N: usize, const M: usize> TryFrom<NonDeDuplicatedCStr<N>> for NonDeDuplicatedStr<M> {
+    type Error = CStrToStrError;
+
+    fn try_from(cstr: NonDeDuplicatedCStr<N>) -> Result<Self, CStrToStrError> {
+        // Not a `static`, so don't drop it (that panics in debug builds).
+        let cstr = core::mem::ManuallyDrop::new(cstr);
+        let bytes = cstr.get().to_bytes();
+        if bytes.len() != M {
+            return Err(CStrToStrError::WrongLength {
+                expected: M,
+                actual: bytes.len(),
+            });
+        }
+        let s = core::str::from_utf8(bytes).map_err(CStrToStrError::NotUtf8)?;
+        Ok(Self::new(s))
+    }
+}
+
+/// A uniform accessor for generic code over [NonDeDuplicated] (where `get()` returns `&T`),
+/// [NonDeDuplicatedStr] (`&str`) and [NonDeDuplicatedCStr] (`&CStr`). Their own `get()` functions
+/// are `const`, but trait methods can't be (on `stable`), so prefer those outside of generic code.
+///
+/// `Target` is like [core::ops::Deref::Target], but [NonDeDuplicatedStr] and [NonDeDuplicatedCStr]
+/// don't implement [Deref](core::ops::Deref). (A reference, rather than a generic associated type,
+/// so that generic code can compare addresses of results.)
+/// ```rust
+/// use ndd::{NddRef, NonDeDuplicated, NonDeDuplicatedStr};
+///
+/// fn all_distinct<W: NddRef>(wrappers: &[&W]) -> bool {
+///     let mut i = 0;
+///     while i < wrappers.len() {
+///         for other in &wrappers[i + 1..] {
+///             if core::ptr::addr_eq(wrappers[i].as_ref_ndd(), other.as_ref_ndd()) {
+///                 return false;
+///             }
+///         }
+///         i += 1;
+///     }
+///     true
+/// }
+///
+/// static A: NonDeDuplicatedStr<1> = NonDeDuplicatedStr::new("a");
+/// static B: NonDeDuplicatedStr<1> = NonDeDuplicatedStr::new("a");
+/// assert!(all_distinct(&[&A, &B]));
+/// assert!(!all_distinct(&[&A, &A]));
+/// ```
+pub trait NddRef {
+    type Target: ?Sized;
+
+    /// Same as `get()` of the implementing type.
+    fn as_ref_ndd(&self) -> &Self::Target;
+}
+
+impl<T: Any + Send + Sync> NddRef for NonDeDuplicated<T> {
+    type Target = T;
+
+    fn as_ref_ndd(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<const N: usize> NddRef for NonDeDuplicatedStr<N> {
+    type Target = str;
+
+    fn as_ref_ndd(&self) -> &str {
+        self.get()
+    }
+}
+
+impl<const N: usize> NddRef for NonDeDuplicatedCStr<N> {
+    type Target = CStr;
+
+    fn as_ref_ndd(&self) -> &CStr {
+        self.get()
+    }
+}
+
+/// Anything that yields a `&str`: for functions generic over string wrappers and plain [str].
+/// Implemented for [NonDeDuplicatedStr], [NonDeDuplicatedAscii] and [str]. Like with [NddRef],
+/// prefer the `const` functions of the wrappers outside of generic code.
+///
+/// Not implemented for [NonDeDuplicatedCStr]: a well-formed C string need not be valid UTF-8.
+/// For that use [CStr::to_str] on [NonDeDuplicatedCStr::get].
+/// ```rust
+/// use ndd::{AsStr, NonDeDuplicatedStr};
+///
+/// fn shout<S: AsStr + ?Sized>(s: &S) -> bool {
+///     s.as_str().ends_with('!')
+/// }
+///
+/// static HEY: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Hey!");
+/// assert!(shout(&HEY));
+/// assert!(!shout("hey"));
+/// ```
+pub trait AsStr {
+    fn as_str(&self) -> &str;
+}
+
+impl AsStr for str {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+/// Same as [NonDeDuplicatedStr::as_str] (which, being inherent, takes precedence in non-generic
+/// code).
+impl<const N: usize> AsStr for NonDeDuplicatedStr<N> {
+    fn as_str(&self) -> &str {
+        self.get()
+    }
+}
+
+impl<const N: usize> AsStr for NonDeDuplicatedAscii<N> {
+    fn as_str(&self) -> &str {
+        self.get()
+    }
+}
+
+/// For now, [Sync] (and [NonDeDuplicatedFlexible] in general) requires that `OWN` is both [Sync]
+/// AND [Send], following
+/// [std::sync::Mutex](https://doc.rust-lang.org/nightly/std/sync/struct.Mutex.html#impl-Sync-for-Mutex%3CT%3E).
+/// However, from <https://doc.rust-lang.org/nightly/core/marker/trait.Sync.html> it seems that `T:
+/// Send` may be unnecessary? Please advise.
+///
+/// Either way, [NonDeDuplicated], [NonDeDuplicatedStr] and [NonDeDuplicatedCStr] (and underlying
+/// [NonDeDuplicatedFlexible]) exist specifically for static variables. Those get never moved out.
+/// So, (unlike [std::sync::Mutex]) they do **not** need to implement [Send].
+///
+/// Also, unclear if `TO` needs to be [Send] and [Sync].
+unsafe impl<OWN: Any + Send + Sync, TO: Any + Send + Sync + ?Sized> Sync
+    for NonDeDuplicatedFlexible<OWN, TO>
+{
+}
+
+/// [Cell] is not [core::panic::RefUnwindSafe], because code could observe its value half-way
+/// mutated after a panic. But `ndd` never mutates it. So, this holds as long as `OWN` itself is
+/// [core::panic::RefUnwindSafe]. ([core::panic::UnwindSafe] is implemented automatically.)
+impl<OWN: Any + Send + Sync + core::panic::RefUnwindSafe, TO: Any + Send + Sync + ?Sized>
+    core::panic::RefUnwindSafe for NonDeDuplicatedFlexible<OWN, TO>
+{
+}
+
+/// [NonDeDuplicated] and friends are intended for `static` (immutable) variables only. So
+/// [Drop::drop] panics in debug/miri builds.
+impl<OWN: Any + Send + Sync, TO: Any + Send + Sync + ?Sized> Drop
+    for NonDeDuplicatedFlexible<OWN, TO>
+{
+    fn drop(&mut self) {
+        // If the client uses Box::leak() or friends, then drop() will NOT happen. That is OK: A
+        // leaked reference will have static lifetime.
+        #[cfg(any(debug_assertions, miri))]
+        panic!("Do not use for local variables, const, or on heap. Use for static variables only.")
+    }
+}
+
+#[cfg(test)]
+mod tests_shared {
+    // Intentionally from a byte array (rather than from a byte string literal), because Rust/LLVM
+    // de-duplicate them differently.
+    #[allow(clippy::byte_char_slices)]
+    pub const STR_CONST_FROM_BYTE_ARRAY_HI: &str = {
+        match str::from_utf8(&[b'H', b'i']) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    };
+
+    pub const STR_CONST_FROM_BYTE_STRING_HELLO: &str = {
+        match str::from_utf8(b"Hello") {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    };
+}
+
+/// These tests don't actually test `ndd`, but they test the behavior **without** `ndd`.
+#[cfg(test)]
+mod tests_without_ndd {
+    use crate::tests_shared::{STR_CONST_FROM_BYTE_ARRAY_HI, STR_CONST_FROM_BYTE_STRING_HELLO};
+    use core::ptr;
+
+    const U8_CONST: u8 = b'A';
+    static U8_STATIC_1: u8 = b'A';
+    static U8_STATIC_2: u8 = b'A';
+
+    #[test]
+    fn addresses_unique_between_statics() {
+        assert!(!ptr::eq(&U8_STATIC_1, &U8_STATIC_2));
+    }
+
+    #[cfg(not(any(debug_assertions, miri)))]
+    /// In release, [U8_CONST] gets optimized away and points to the same address as
+    /// [U8_STATIC_1]!
+    #[should_panic(expected = "assertion failed: !ptr::eq(&U8_STATIC_1, &U8_CONST)")]
+    #[test]
+    fn u8_global_const_and_global_static_release() {
+        assert!(!ptr::eq(&U8_STATIC_1, &U8_CONST));
+    }
+    #[cfg(any(debug_assertions, miri))]
+    /// In debug/MIRI, [ARR_CONST] and [ARR_STATIC_1] have unique/separate addresses!
+    #[test]
+    fn u8_global_const_global_and_static_debug_and_miri() {
+        assert!(!ptr::eq(&U8_STATIC_1, &U8_CONST));
+    }
+
+    #[cfg(not(miri))]
+    #[test]
+    #[should_panic(expected = "assertion failed: !ptr::eq(STR_CONST_FROM_BYTE_ARRAY_HI, \"Hi\")")]
+    fn str_global_byte_slice_const_and_local_str_release_and_debug() {
+        assert!(!ptr::eq(STR_CONST_FROM_BYTE_ARRAY_HI, "Hi"));
+    }
+    #[cfg(miri)]
+    #[test]
+    fn str_global_byte_slice_const_and_local_str_miri() {
+        assert!(!ptr::eq(STR_CONST_FROM_BYTE_ARRAY_HI, "Hi"));
+    }
+
+    /// This is the same for all three: release, debug AND miri!
+    #[test]
+    fn str_global_byte_by_byte_const_and_local_static() {
+        assert!(ptr::eq(STR_CONST_FROM_BYTE_STRING_HELLO, "Hello"));
+    }
+
+    static STR_STATIC: &str = "Ciao";
+
+    #[cfg(not(miri))]
+    #[should_panic(expected = "assertion failed: !ptr::eq(local_const_based_slice, STR_STATIC)")]
+    #[test]
+    fn str_local_const_based_and_global_static_release_and_debug() {
+        str_local_const_based_and_global_static_impl();
+    }
+    #[cfg(miri)]
+    #[test]
+    fn str_local_const_based_and_global_static_miri() {
+        str_local_const_based_and_global_static_impl();
+    }
+    fn str_local_const_based_and_global_static_impl() {
+        const LOCAL_CONST_ARR: [u8; 4] = [b'C', b'i', b'a', b'o'];
+        let local_const_based_slice: &str = str::from_utf8(&LOCAL_CONST_ARR).unwrap();
+        assert!(!ptr::eq(local_const_based_slice, STR_STATIC));
+    }
+
+    mod cross_module_static {
+        pub static STATIC_OPT_U8_A: Option<u8> = Some(b'A');
+    }
+    mod cross_module_const {
+        use core::ptr;
+        pub const CONST_OPT_U8_A: Option<u8> = Some(b'A');
+
+        #[cfg(not(any(debug_assertions, miri)))]
+        #[test]
+        #[should_panic(
+            expected = "assertion failed: !ptr::eq(&CONST_OPT_U8_A, &super::cross_module_static::STATIC_OPT_U8_A)"
+        )]
+        fn option_u8_global_const_global_static_release() {
+            assert!(!ptr::eq(
+                &CONST_OPT_U8_A,
+                &super::cross_module_static::STATIC_OPT_U8_A
+            ));
+        }
+        #[cfg(any(debug_assertions, miri))]
+        #[test]
+        fn option_u8_global_const_global_static_debug_and_miri() {
+            assert!(!ptr::eq(
+                &CONST_OPT_U8_A,
+                &super::cross_module_static::STATIC_OPT_U8_A
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_with_ndd {
+    use super::*;
+    use crate::tests_shared::{STR_CONST_FROM_BYTE_ARRAY_HI, STR_CONST_FROM_BYTE_STRING_HELLO};
+    use core::ptr;
+
+    const U8_CONST: u8 = b'A';
+    static U8_STATIC_1: u8 = b'A';
+    static U8_STATIC_2: u8 = b'A';
+
+    #[allow(dead_code)]
+    const fn expect_sync_ref<T: Sync>() {}
+    #[allow(dead_code)]
+    const _: () = expect_sync_ref::<NonDeDuplicated<u8>>();
+
+    static U8_NDD: NonDeDuplicated<u8> = NonDeDuplicated::new(U8_CONST);
+    static U8_NDD_REF: &u8 = U8_NDD.get();
+    #[test]
+    fn u8_global_const_and_ndd() {
+        assert!(!ptr::eq(U8_NDD_REF, &U8_CONST));
+        assert!(!ptr::eq(U8_NDD_REF, &U8_STATIC_1));
+        assert!(!ptr::eq(U8_NDD_REF, &U8_STATIC_2));
+    }
+    #[cfg(not(any(debug_assertions, miri)))]
+    /// In release, a `static` (without `ndd`) gets the same address as [U8_CONST] (see
+    /// `tests_without_ndd::u8_global_const_and_global_static_release`). [U8_NDD] must not, even
+    /// though it's constructed from [U8_CONST].
+    #[test]
+    fn u8_global_const_and_ndd_release() {
+        assert!(!ptr::eq(U8_NDD.get(), &U8_CONST));
+        assert!(!ptr::eq(U8_NDD_SAME_VALUE.get(), &U8_CONST));
+    }
+
+    static BYTES16_NDD: NonDeDuplicatedBytes16 =
+        NonDeDuplicatedBytes16::new_from_u128(0x0011_2233_4455_6677_8899_aabb_ccdd_eeff);
+    #[test]
+    fn bytes16_ndd_from_u128() {
+        assert_eq!(
+            BYTES16_NDD.get(),
+            &[
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+                0xee, 0xff
+            ]
+        );
+        assert_eq!(
+            u128::from_be_bytes(*BYTES16_NDD.get()),
+            0x0011_2233_4455_6677_8899_aabb_ccdd_eeff
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", U8_NDD), format!("{}", b'A'));
+        assert_eq!(format!("{:>4}", U8_NDD), "  65");
+    }
+
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+    impl Point {
+        fn sum(&self) -> u8 {
+            self.x + self.y
+        }
+    }
+    static POINT_NDD: NonDeDuplicated<Point> = NonDeDuplicated::new(Point { x: 1, y: 2 });
+    #[test]
+    fn deref() {
+        assert_eq!(POINT_NDD.sum(), 3);
+        assert!(ptr::eq(&*POINT_NDD, POINT_NDD.get()));
+    }
+
+    /// Its methods have the same names as methods of [NonDeDuplicated].
+    struct Shadowing(u8);
+    impl Shadowing {
+        fn get(&self) -> u8 {
+            self.0
+        }
+        fn ref_eq(&self, _: &Self) -> &'static str {
+            "inner"
+        }
+        fn inner_only(&self) -> u8 {
+            self.0 + 1
+        }
+    }
+    static SHADOWING_NDD: NonDeDuplicated<Shadowing> = NonDeDuplicated::new(Shadowing(7));
+    #[test]
+    fn deref_does_not_shadow_wrapper_methods() {
+        // Wrapper methods win.
+        let inner: &Shadowing = SHADOWING_NDD.get();
+        assert!(SHADOWING_NDD.ref_eq(&SHADOWING_NDD));
+        // Inner methods are reachable explicitly.
+        assert_eq!(SHADOWING_NDD.get().get(), 7);
+        assert_eq!((*SHADOWING_NDD).get(), 7);
+        assert_eq!((*SHADOWING_NDD).ref_eq(inner), "inner");
+        // And directly, if the wrapper has no such method.
+        assert_eq!(SHADOWING_NDD.inner_only(), 8);
+    }
+
+    #[test]
+    fn debug() {
+        let u8_debug = format!("{:?}", U8_NDD);
+        assert!(u8_debug.contains("own: u8, to: u8"));
+        assert!(u8_debug.contains(&format!("addr: {:p}", U8_NDD.get())));
+
+        let cstr_debug = format!("{:?}", CSTR_NDD_CIAO);
+        assert!(cstr_debug.contains("own: [u8; 5]"));
+        assert!(cstr_debug.contains("CStr"));
+        assert!(!cstr_debug.contains("Ciao"));
+    }
+
+    static STR_NDD_SECRET: NonDeDuplicatedStr<7> = NonDeDuplicatedStr::new("hunter2");
+    #[test]
+    fn debug_without_value() {
+        let debug = format!("{:?}", STR_NDD_SECRET);
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains(&format!("{:p}", STR_NDD_SECRET.get().as_ptr())));
+        assert!(!format!("{:#?}", STR_NDD_SECRET).contains("hunter2"));
+    }
+
+    /// `Cell` (and `repr(transparent)`) add no overhead.
+    #[test]
+    fn layout_same_as_inner() {
+        use core::mem::{align_of, size_of};
+        assert_eq!(size_of::<NonDeDuplicated<u8>>(), size_of::<u8>());
+        assert_eq!(size_of::<NonDeDuplicated<u64>>(), size_of::<u64>());
+        assert_eq!(align_of::<NonDeDuplicated<u64>>(), align_of::<u64>());
+        assert_eq!(
+            size_of::<NonDeDuplicated<Option<u8>>>(),
+            size_of::<Option<u8>>()
+        );
+        assert_eq!(size_of::<NonDeDuplicatedStr<5>>(), 5);
+        assert_eq!(align_of::<NonDeDuplicatedStr<5>>(), 1);
+        assert!(ptr::eq(U8_NDD.get(), ptr::from_ref(&U8_NDD).cast::<u8>()));
+    }
+
+    #[repr(C)]
+    struct FfiRecord {
+        tag: u8,
+        value: u32,
+    }
+    static FFI_RECORD_NDD: NonDeDuplicated<FfiRecord> =
+        NonDeDuplicated::new(FfiRecord { tag: 1, value: 2 });
+    #[test]
+    fn layout_repr_c_inner() {
+        use core::mem::{align_of, offset_of, size_of};
+        assert_eq!(
+            size_of::<NonDeDuplicated<FfiRecord>>(),
+            size_of::<FfiRecord>()
+        );
+        assert_eq!(
+            align_of::<NonDeDuplicated<FfiRecord>>(),
+            align_of::<FfiRecord>()
+        );
+        // What C code would see, given a pointer to the static.
+        let record = unsafe { &*ptr::from_ref(&FFI_RECORD_NDD).cast::<FfiRecord>() };
+        assert_eq!((record.tag, record.value), (1, 2));
+        assert_eq!(offset_of!(FfiRecord, value), 4);
+    }
+
+    #[test]
+    fn eq_ref_inner() {
+        assert_eq!(U8_NDD, &b'A');
+        assert_ne!(U8_NDD, &b'B');
+    }
+
+    static U32_NDD_1: NonDeDuplicated<u32> = NonDeDuplicated::new(1);
+    static U32_NDD_2: NonDeDuplicated<u32> = NonDeDuplicated::new(2);
+    static U32_NDD_3: NonDeDuplicated<u32> = NonDeDuplicated::new(3);
+    #[test]
+    fn ord_by_value() {
+        let mut sorted = [&U32_NDD_3, &U32_NDD_1, &U32_NDD_2];
+        sorted.sort();
+        assert!(ptr::eq(sorted[0], &U32_NDD_1));
+        assert!(ptr::eq(sorted[1], &U32_NDD_2));
+        assert!(ptr::eq(sorted[2], &U32_NDD_3));
+        assert!(U32_NDD_1 < U32_NDD_2);
+    }
+
+    #[test]
+    fn as_erased_ptr() {
+        let erased = [
+            U8_NDD.as_erased_ptr(),
+            U8_NDD_SAME_VALUE.as_erased_ptr(),
+            STR_NDD_CIAO.as_erased_ptr(),
+            CSTR_NDD_CIAO.as_erased_ptr(),
+        ];
+        crate::testing::within_process_distinct(&erased.map(|p| unsafe { &*p }));
+        assert!(ptr::eq(erased[0], ptr::from_ref(U8_NDD.get()).cast()));
+        assert!(ptr::eq(erased[2], STR_NDD_CIAO.get().as_ptr().cast()));
+    }
+
+    struct SyncPtr(*const u8);
+    unsafe impl Sync for SyncPtr {}
+    const U8_NDD_PTR: *const u8 = U8_NDD.as_ptr_const();
+    static U8_NDD_PTR_STATIC: SyncPtr = SyncPtr(U8_NDD.as_ptr_const());
+    #[test]
+    fn as_ptr_const() {
+        assert!(ptr::eq(U8_NDD_PTR, U8_NDD.get()));
+        assert!(ptr::eq(U8_NDD_PTR_STATIC.0, U8_NDD.get()));
+    }
+
+    #[test]
+    fn addr_and_ref() {
+        let table = [
+            (U8_NDD.addr_and_ref().0, "U8_NDD"),
+            (U8_NDD_SAME_VALUE.addr_and_ref().0, "U8_NDD_SAME_VALUE"),
+            (STR_NDD_CIAO.addr_and_ref().0, "STR_NDD_CIAO"),
+            (CSTR_NDD_CIAO.addr_and_ref().0, "CSTR_NDD_CIAO"),
+        ];
+        for (i, (addr, name)) in table.iter().enumerate() {
+            for (other_addr, other_name) in &table[i + 1..] {
+                assert_ne!(
+                    addr, other_addr,
+                    "{name} and {other_name} share an address."
+                );
+            }
+        }
+        assert_eq!(
+            U8_NDD.addr_and_ref(),
+            (U8_NDD.get() as *const u8 as usize, &b'A')
+        );
+        assert_eq!(STR_NDD_CIAO.addr_and_ref().1, "Ciao");
+        assert_eq!(CSTR_NDD_CIAO.addr_and_ref().1, c"Ciao");
+    }
+
+    fn first_and_len<W: NddRef>(wrapper: &W, len: impl Fn(&W::Target) -> usize) -> (usize, usize) {
+        let target = wrapper.as_ref_ndd();
+        (ptr::from_ref(target).cast::<u8>() as usize, len(target))
+    }
+    #[test]
+    fn ndd_ref_generic() {
+        let u8s = first_and_len(&U8S_NDD_ABC, |arr| arr.len());
+        let ciao = first_and_len(&STR_NDD_CIAO, str::len);
+        let cstr = first_and_len(&CSTR_NDD_CIAO, |c| c.to_bytes().len());
+        assert_eq!(u8s, (U8S_NDD_ABC.get().as_ptr() as usize, 3));
+        assert_eq!(ciao, (STR_NDD_CIAO.get().as_ptr() as usize, 4));
+        assert_eq!(cstr, (CSTR_NDD_CIAO.get().as_ptr() as usize, 4));
+    }
+
+    fn as_str_len<S: AsStr + ?Sized>(s: &S) -> usize {
+        s.as_str().len()
+    }
+    #[test]
+    fn as_str_generic() {
+        assert_eq!(as_str_len(&STR_NDD_CIAO), 4);
+        assert_eq!(as_str_len(&ASCII_NDD_TOKEN), 5);
+        assert_eq!(as_str_len("plain"), 5);
+        assert!(ptr::eq(AsStr::as_str(&STR_NDD_CIAO), STR_NDD_CIAO.get()));
+    }
+
+    #[test]
+    fn catch_unwind() {
+        let u8_ndd: &NonDeDuplicated<u8> = &U8_NDD;
+        let str_ndd: &NonDeDuplicatedStr<4> = &STR_NDD_CIAO;
+        let result = std::panic::catch_unwind(|| (*u8_ndd.get(), str_ndd.get().len()));
+        assert_eq!(result.unwrap(), (b'A', 4));
+    }
+
+    static REF_NDD_1: NonDeDuplicatedRef<u8> = NonDeDuplicatedRef::new(&U8_STATIC_1);
+    static REF_NDD_2: NonDeDuplicatedRef<u8> = NonDeDuplicatedRef::new(&U8_STATIC_1);
+    static REF_NDD_STR: NonDeDuplicatedRef<str> = NonDeDuplicatedRef::new("Ref");
+    #[test]
+    fn ref_ndd() {
+        assert!(ptr::eq(REF_NDD_1.get(), &U8_STATIC_1));
+        assert!(ptr::eq(REF_NDD_2.get(), &U8_STATIC_1));
+        assert!(!ptr::eq(
+            REF_NDD_1.as_erased_ptr(),
+            REF_NDD_2.as_erased_ptr()
+        ));
+        assert_eq!(REF_NDD_STR.get(), "Ref");
+    }
+
+    static U8_NDD_SAME_VALUE: NonDeDuplicated<u8> = NonDeDuplicated::new(U8_CONST);
+    #[test]
+    fn val_eq_and_ref_eq() {
+        assert!(U8_NDD.val_eq(&U8_NDD_SAME_VALUE));
+        assert!(!U8_NDD.ref_eq(&U8_NDD_SAME_VALUE));
+        assert!(U8_NDD.ref_eq(&U8_NDD));
+
+        assert!(STR_NDD_HI.val_eq(&STR_NDD_HELLO));
+        assert!(!STR_NDD_HI.ref_eq(&STR_NDD_HELLO));
+        assert!(!STR_NDD_HI.val_eq(&STR_NDD_WORLD));
+    }
+
+    // A false positive: AddrHashed hashes by address only, so the inner Cell doesn't matter.
+    #[allow(clippy::mutable_key_type)]
+    #[test]
+    fn addr_hashed() {
+        let mut set = std::collections::HashSet::new();
+        assert!(set.insert(AddrHashed(&U8_NDD)));
+        assert!(set.insert(AddrHashed(&U8_NDD_SAME_VALUE)));
+        assert!(!set.insert(AddrHashed(&U8_NDD)));
+        assert_eq!(set.len(), 2);
+    }
+
+    static U8S_NDD_ABC: NonDeDuplicated<[u8; 3]> = NonDeDuplicated::new(*b"abc");
+    #[test]
+    fn eq_bytes() {
+        static U8S_EQ: bool = U8S_NDD_ABC.eq_bytes(b"abc");
+        assert!(U8S_EQ);
+        assert!(!U8S_NDD_ABC.eq_bytes(b"abd"));
+        assert!(!U8S_NDD_ABC.eq_bytes(b"ab"));
+        assert!(!U8S_NDD_ABC.eq_bytes(b"abcd"));
+
+        static STR_EQ: bool = STR_NDD_CIAO.eq_bytes(b"Ciao");
+        assert!(STR_EQ);
+        assert!(!STR_NDD_CIAO.eq_bytes(b"Ciaa"));
+        assert!(!STR_NDD_CIAO.eq_bytes(b"Cia"));
+        assert!(!STR_NDD_CIAO.eq_bytes(b""));
+    }
+
+    #[test]
+    fn bytes_slice_methods_through_deref() {
+        assert!(U8S_NDD_ABC.iter().rev().eq(b"cba"));
+        assert!(U8S_NDD_ABC.starts_with(b"ab"));
+        assert_eq!(U8S_NDD_ABC.len(), 3);
+        let slice: &[u8] = &U8S_NDD_ABC[1..];
+        assert!(ptr::eq(&slice[0], &U8S_NDD_ABC.get()[1]));
+    }
+
+    const BOOL_CONST_TRUE: bool = true;
+    static BOOL_NDD_TRUE_1: NonDeDuplicatedBool = NonDeDuplicated::new(true);
+    static BOOL_NDD_TRUE_2: NonDeDuplicatedBool = NonDeDuplicated::new(BOOL_CONST_TRUE);
+    #[test]
+    fn bool_ndd_true() {
+        assert!(!ptr::eq(BOOL_NDD_TRUE_1.get(), BOOL_NDD_TRUE_2.get()));
+        assert!(!ptr::eq(BOOL_NDD_TRUE_1.get(), &BOOL_CONST_TRUE));
+        assert!(!ptr::eq(BOOL_NDD_TRUE_2.get(), &BOOL_CONST_TRUE));
+        assert!(!ptr::eq(BOOL_NDD_TRUE_1.get(), &true));
+    }
+
+    static STR_NDD_HI: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("Hello");
+    #[test]
+    fn str_ndd_hi() {
+        assert!(!ptr::eq(STR_NDD_HI.get(), "Hi"));
+        assert!(!ptr::eq(STR_NDD_HI.get(), STR_CONST_FROM_BYTE_ARRAY_HI));
+        assert!(!ptr::eq(STR_NDD_HI.get(), STR_CONST_FROM_BYTE_STRING_HELLO));
+    }
+
+    static STR_NDD_SAME_1: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Same");
+    static STR_NDD_SAME_2: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Same");
+    /// Also run on `wasm32` (see [GitHub Actions]), where memory model and LLVM merging differ.
+    #[test]
+    fn str_ndd_identical_statics() {
+        assert!(!ptr::eq(STR_NDD_SAME_1.get(), STR_NDD_SAME_2.get()));
+        assert!(!ptr::eq(STR_NDD_SAME_1.get(), "Same"));
+        assert!(!ptr::eq(STR_NDD_SAME_2.get(), "Same"));
+    }
+
+    static STR_NDD_CIAO: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Ciao");
+    #[test]
+    fn str_local_const_based_and_str_ndd() {
+        const LOCAL_CONST_ARR: [u8; 4] = [b'C', b'i', b'a', b'o'];
+        let local_const_based_slice: &str = str::from_utf8(&LOCAL_CONST_ARR).unwrap();
+        assert!(!ptr::eq(local_const_based_slice, STR_NDD_CIAO.get()));
+    }
+
+    const U8S_CONST_ZEROED: [u8; 4] = [0; 4];
+    static U8S_NDD_ZEROED: NonDeDuplicated<[u8; 4]> = NonDeDuplicated::new_zeroed();
+    #[test]
+    fn u8s_ndd_zeroed() {
+        assert_eq!(U8S_NDD_ZEROED.get(), &[0; 4]);
+        assert!(!ptr::eq(U8S_NDD_ZEROED.get(), &U8S_CONST_ZEROED));
+    }
+
+    #[test]
+    fn str_ndd_into_static_str() {
+        let s: &'static str = (&STR_NDD_CIAO).into();
+        assert_eq!(s, "Ciao");
+        assert!(ptr::eq(s, STR_NDD_CIAO.get()));
+    }
+
+    #[test]
+    fn str_buf_write_then_freeze() {
+        use core::fmt::Write;
+
+        let mut buf = NonDeDuplicatedStrBuf::<8>::new();
+        let n = 42;
+        write!(buf, "Hi-{n}").unwrap();
+        assert_eq!(buf.as_str(), "Hi-42");
+
+        let frozen: NonDeDuplicatedStr<5> = buf.freeze();
+        assert_eq!(frozen.get(), "Hi-42");
+        // Instead of Box::leak(), which MIRI would report as a memory leak.
+        core::mem::forget(frozen);
+    }
+
+    #[test]
+    fn str_buf_as_bytes_and_padded() {
+        use core::fmt::Write;
+
+        let mut buf = NonDeDuplicatedStrBuf::<16>::new();
+        write!(buf, "Pad").unwrap();
+        assert_eq!(buf.as_bytes(), b"Pad");
+        assert_eq!(buf.as_bytes_padded().len(), 16);
+        assert_eq!(&buf.as_bytes_padded()[..3], b"Pad");
+        assert!(buf.as_bytes_padded()[3..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn str_buf_remaining() {
+        use core::fmt::Write;
+
+        let mut buf = NonDeDuplicatedStrBuf::<4>::new();
+        assert_eq!(buf.remaining(), 4);
+        write!(buf, "Re").unwrap();
+        assert_eq!(buf.remaining(), 2);
+        write!(buf, "st").unwrap();
+        assert_eq!(buf.remaining(), 0);
+    }
+
+    #[test]
+    fn str_buf_new_padded_with() {
+        let spaces = NonDeDuplicatedStrBuf::<6>::new_padded_with("Pad", b' ');
+        assert_eq!(spaces.as_str(), "Pad");
+        assert_eq!(spaces.as_bytes_padded(), b"Pad   ");
+
+        let zeros = NonDeDuplicatedStrBuf::<5>::new_padded_with("Pad", 0);
+        assert_eq!(zeros.as_bytes(), b"Pad");
+        assert_eq!(zeros.as_bytes_padded(), b"Pad\0\0");
+        assert_eq!(zeros.remaining(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "The string doesn't fit in the capacity.")]
+    fn str_buf_new_padded_with_too_long() {
+        let _ = NonDeDuplicatedStrBuf::<2>::new_padded_with("Pad", b' ');
+    }
+
+    #[test]
+    fn str_buf_new_truncating() {
+        static EXACT: NonDeDuplicatedStrBuf<4> = NonDeDuplicatedStrBuf::new_truncating("Ciao");
+        assert_eq!(EXACT.as_str(), "Ciao");
+
+        let shorter = NonDeDuplicatedStrBuf::<6>::new_truncating("Ciao");
+        assert_eq!(shorter.as_str(), "Ciao");
+        assert_eq!(shorter.as_bytes_padded(), b"Ciao\0\0");
+
+        let over_long = NonDeDuplicatedStrBuf::<3>::new_truncating("Ciao");
+        assert_eq!(over_long.as_str(), "Cia");
+        // 'é' is 2 bytes (at 3..5), so a cut at 4 moves back to 3.
+        let mid_char = NonDeDuplicatedStrBuf::<4>::new_truncating("café");
+        assert_eq!(mid_char.as_str(), "caf");
+        assert_eq!(mid_char.remaining(), 1);
+    }
+
+    #[test]
+    fn str_buf_overflow() {
+        use core::fmt::Write;
+
+        let mut buf = NonDeDuplicatedStrBuf::<4>::new();
+        buf.write_str("Hi").unwrap();
+        assert!(buf.write_str("Hey").is_err());
+        assert_eq!(buf.as_str(), "Hi");
+    }
+
+    #[test]
+    fn str_buf_overflow_partway_keeps_earlier_pieces() {
+        use core::fmt::Write;
+
+        let mut buf = NonDeDuplicatedStrBuf::<4>::new();
+        let n = 42;
+        // "Hi-" fits, then "42" doesn't.
+        assert!(write!(buf, "Hi-{n}").is_err());
+        assert_eq!(buf.as_str(), "Hi-");
+        assert_eq!(buf.remaining(), 1);
+    }
+
+    #[test]
+    fn as_array() {
+        assert_eq!(STR_NDD_CIAO.as_array(), b"Ciao");
+        assert!(ptr::eq(
+            STR_NDD_CIAO.as_array().as_ptr(),
+            STR_NDD_CIAO.get().as_bytes().as_ptr()
+        ));
+        assert_eq!(CSTR_NDD_CIAO.as_array(), b"Ciao\0");
+        assert!(ptr::eq(
+            CSTR_NDD_CIAO.as_array().as_ptr(),
+            CSTR_NDD_CIAO.get().as_ptr().cast()
+        ));
+    }
+
+    static STR_NDD_CAFE: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("café");
+    static CSTR_NDD_CAFE: NonDeDuplicatedCStr<6> = NonDeDuplicatedCStr::new_from_str("café");
+    #[test]
+    fn is_ascii() {
+        // A `const` can't read (interior mutable) `static` data, but another `static` can.
+        static CIAO_IS_ASCII: bool = STR_NDD_CIAO.is_ascii();
+        assert!(CIAO_IS_ASCII);
+        assert!(CSTR_NDD_CIAO.is_ascii());
+        assert!(!STR_NDD_CAFE.is_ascii());
+        assert!(!CSTR_NDD_CAFE.is_ascii());
+    }
+
+    static STR_NDD_WITH_NUL: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Ci\0o");
+    #[test]
+    fn has_no_interior_nul() {
+        static CIAO_NUL_FREE: bool = STR_NDD_CIAO.has_no_interior_nul();
+        assert!(CIAO_NUL_FREE);
+        assert!(STR_NDD_CAFE.has_no_interior_nul());
+        assert!(!STR_NDD_WITH_NUL.has_no_interior_nul());
+    }
+
+    #[test]
+    fn as_str() {
+        static CIAO_LEN: usize = STR_NDD_CIAO.as_str().len();
+        assert_eq!(CIAO_LEN, 4);
+        assert!(ptr::eq(STR_NDD_CIAO.as_str(), STR_NDD_CIAO.get()));
+    }
+
+    static STR_NDD_HI_SUM: NonDeDuplicatedStr<2> = NonDeDuplicatedStr::new("Hi");
+    #[test]
+    fn bytes() {
+        let sum: u32 = STR_NDD_HI_SUM.bytes().map(u32::from).sum();
+        assert_eq!(sum, u32::from(b'H') + u32::from(b'i'));
+    }
+
+    #[test]
+    fn into_iter_chars() {
+        let mut expected = ['c', 'a', 'f', 'é'].into_iter();
+        for c in &STR_NDD_CAFE {
+            assert_eq!(Some(c), expected.next());
+        }
+        assert_eq!(expected.next(), None);
+    }
+
+    static STR_NDD_MAX_N_EXACT: NonDeDuplicatedStr<3> =
+        NonDeDuplicatedStr::new_with_max_n::<3>("Max");
+    static CSTR_NDD_MAX_N_EXACT: NonDeDuplicatedCStr<4> =
+        NonDeDuplicatedCStr::new_from_str_with_max_n::<4>("Max");
+    #[test]
+    fn new_with_max_n() {
+        assert_eq!(STR_NDD_MAX_N_EXACT.get(), "Max");
+        assert_eq!(CSTR_NDD_MAX_N_EXACT.get(), c"Max");
+        assert_eq!(crate::MAX_N, 1024 * 1024);
+    }
+
+    static STR_NDD_FROM_STATIC: NonDeDuplicatedStr<6> =
+        NonDeDuplicatedStr::new_from_static_str("Static");
+    #[test]
+    fn str_ndd_new_from_static_str() {
+        let literal: &'static str = "Static";
+        assert_eq!(STR_NDD_FROM_STATIC.get(), literal);
+        assert!(!ptr::eq(STR_NDD_FROM_STATIC.get(), literal));
+    }
+
+    #[test]
+    fn str_ndd_substr() {
+        let sub = STR_NDD_CAFE.substr(1, 3);
+        assert_eq!(sub, "af");
+        assert!(ptr::eq(sub.as_ptr(), STR_NDD_CAFE.get()[1..].as_ptr()));
+        assert_eq!(STR_NDD_CAFE.substr(3, 5), "é");
+        assert_eq!(STR_NDD_CAFE.substr(5, 5), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "The byte range must start and end on char boundaries.")]
+    fn str_ndd_substr_not_char_boundary() {
+        let _ = STR_NDD_CAFE.substr(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "The byte range is out of bounds.")]
+    fn str_ndd_substr_out_of_bounds() {
+        let _ = STR_NDD_CAFE.substr(2, 6);
+    }
+
+    #[test]
+    fn str_ndd_eq_byte_array() {
+        assert!(STR_NDD_CIAO == *b"Ciao");
+        assert!(STR_NDD_CIAO != *b"Hola");
+        assert!(U8S_NDD_ABC == *b"abc");
+        assert!(U8S_NDD_ABC != *b"abd");
+    }
+
+    static STR_NDD_CHAR_A: NonDeDuplicatedStr<{ crate::char_str_len('A') }> =
+        NonDeDuplicatedStr::new_from_char('A');
+    static STR_NDD_CHAR_E_ACUTE: NonDeDuplicatedStr<2> = NonDeDuplicatedStr::new_from_char('é');
+    #[test]
+    fn str_ndd_new_from_char() {
+        assert_eq!(STR_NDD_CHAR_A.get(), "A");
+        assert_eq!(STR_NDD_CHAR_E_ACUTE.get(), "é");
+        assert_eq!(crate::char_str_len('é'), 2);
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(expected = "Target length is 1 byte too large.")]
+    fn str_ndd_new_from_char_wrong_len() {
+        let _ = NonDeDuplicatedStr::<3>::new_from_char('é');
+    }
+
+    static STR_NDD_CHARS_ASCII: NonDeDuplicatedStr<3> =
+        NonDeDuplicatedStr::new_from_chars(&['a', 'b', 'c']);
+    const CHARS_MIXED: &[char] = &['c', 'a', 'f', 'é', '€', '🦀'];
+    static STR_NDD_CHARS_MIXED: NonDeDuplicatedStr<{ crate::chars_utf8_len(CHARS_MIXED) }> =
+        NonDeDuplicatedStr::new_from_chars(CHARS_MIXED);
+    #[test]
+    fn str_ndd_new_from_chars() {
+        assert_eq!(STR_NDD_CHARS_ASCII.get(), "abc");
+        assert_eq!(STR_NDD_CHARS_MIXED.get(), "café€🦀");
+        assert_eq!(crate::chars_utf8_len(CHARS_MIXED), 3 + 2 + 3 + 4);
+        assert_eq!(crate::chars_utf8_len(&[]), 0);
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(expected = "N is 2, but the chars have 3 byte(s): N is 1 too small.")]
+    fn str_ndd_new_from_chars_wrong_len() {
+        let _ = NonDeDuplicatedStr::<2>::new_from_chars(&['a', 'é']);
+    }
+
+    #[test]
+    fn fits() {
+        assert!(NonDeDuplicatedStr::<4>::fits("Ciao"));
+        assert!(!NonDeDuplicatedStr::<4>::fits("Hi"));
+        assert!(!NonDeDuplicatedStr::<4>::fits("café"));
+        assert!(NonDeDuplicatedCStr::<5>::fits("Ciao"));
+        assert!(!NonDeDuplicatedCStr::<4>::fits("Ciao"));
+    }
+
+    static STR_NDD_CHECKED: NonDeDuplicatedStr<7> = NonDeDuplicatedStr::new_checked("Checked");
+    #[test]
+    fn str_ndd_new_checked() {
+        assert_eq!(STR_NDD_CHECKED.get(), "Checked");
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(expected = "N is 12, but the string has 7 byte(s): N is 5 too large.")]
+    fn str_ndd_new_checked_too_large() {
+        let _ = NonDeDuplicatedStr::<12>::new_checked("Checked");
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(expected = "N is 3, but the string has 7 byte(s): N is 4 too small.")]
+    fn str_ndd_new_checked_too_small() {
+        let _ = NonDeDuplicatedStr::<3>::new_checked("Checked");
+    }
+
+    static STR_NDD_FROM_ARRAY: NonDeDuplicatedStr<5> =
+        NonDeDuplicatedStr::new_from_array([b'A', b'r', b'r', 0xC3, 0xA9]);
+    #[test]
+    fn str_ndd_new_from_array() {
+        assert_eq!(STR_NDD_FROM_ARRAY.get(), "Arré");
     }
-}
 
-#[cfg(test)]
-mod tests_shared {
-    pub const STR_CONST_FROM_BYTE_ARRAY_HI: &str = {
-        match str::from_utf8(&[b'H', b'i']) {
-            Ok(s) => s,
-            Err(_) => unreachable!(),
-        }
-    };
+    #[test]
+    #[should_panic(expected = "The bytes must be valid UTF-8.")]
+    fn str_ndd_new_from_array_invalid_utf8() {
+        let _ = NonDeDuplicatedStr::new_from_array([b'A', 0xC3]);
+    }
 
-    pub const STR_CONST_FROM_BYTE_STRING_HELLO: &str = {
-        match str::from_utf8(b"Hello") {
-            Ok(s) => s,
-            Err(_) => unreachable!(),
-        }
-    };
-}
+    static U8S_NDD_CONCAT: NonDeDuplicated<[u8; 4]> = NonDeDuplicated::new_concat(&[b"AB", b"CD"]);
+    const U8S_TABLE: &[u8] = &[1, 2, 4, 8];
+    static U8S_NDD_FROM_TABLE: NonDeDuplicated<[u8; 4]> =
+        NonDeDuplicated::new_from_static_slice(U8S_TABLE);
+    #[test]
+    fn u8s_ndd_new_from_static_slice() {
+        assert_eq!(U8S_NDD_FROM_TABLE.get(), U8S_TABLE);
+        assert!(!ptr::eq(U8S_NDD_FROM_TABLE.get().as_slice(), U8S_TABLE));
+    }
 
-/// These tests don't actually test `ndd`, but they test the behavior **without** `ndd`.
-#[cfg(test)]
-mod tests_without_ndd {
-    use crate::tests_shared::{STR_CONST_FROM_BYTE_ARRAY_HI, STR_CONST_FROM_BYTE_STRING_HELLO};
-    use core::ptr;
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(expected = "N is 5, but the slice has 4 byte(s): N is 1 too large.")]
+    fn u8s_ndd_new_from_static_slice_wrong_len() {
+        let _ = NonDeDuplicated::<[u8; 5]>::new_from_static_slice(U8S_TABLE);
+    }
 
-    const U8_CONST: u8 = b'A';
-    static U8_STATIC_1: u8 = b'A';
-    static U8_STATIC_2: u8 = b'A';
+    static U8S_NDD_KEY_1: NonDeDuplicated<[u8; 4]> = NonDeDuplicated::new(*b"key1");
+    static U8S_NDD_KEY_2: NonDeDuplicated<[u8; 4]> = NonDeDuplicated::new(*b"key2");
+    // A false positive: there is no mutation API, so the inner Cell doesn't change hashes.
+    #[allow(clippy::mutable_key_type)]
+    #[test]
+    fn u8s_ndd_borrow_as_map_key() {
+        // Keyed by references, so dropping the map doesn't drop any `ndd` instances.
+        let mut map = std::collections::HashMap::new();
+        map.insert(&U8S_NDD_KEY_1, 1);
+        map.insert(&U8S_NDD_KEY_2, 2);
+        assert_eq!(map.get(&b"key1"[..]), Some(&1));
+        assert_eq!(map.get(b"key2".as_slice()), Some(&2));
+        assert_eq!(map.get(&b"key3"[..]), None);
+    }
 
     #[test]
-    fn addresses_unique_between_statics() {
-        assert!(!ptr::eq(&U8_STATIC_1, &U8_STATIC_2));
+    fn u8s_ndd_prefix_array() {
+        static FIRST_TWO: &[u8; 2] = U8S_NDD_FROM_TABLE.prefix_array();
+        assert_eq!(FIRST_TWO, &[1, 2]);
+        assert!(ptr::eq(
+            FIRST_TWO.as_ptr(),
+            U8S_NDD_FROM_TABLE.get().as_ptr()
+        ));
+        let all: &[u8; 4] = U8S_NDD_FROM_TABLE.prefix_array();
+        assert!(ptr::eq(all, U8S_NDD_FROM_TABLE.get()));
+        let none: &[u8; 0] = U8S_NDD_FROM_TABLE.prefix_array();
+        assert!(none.is_empty());
     }
 
-    #[cfg(not(any(debug_assertions, miri)))]
-    /// In release, [U8_CONST] gets optimized away and points to the same address as
-    /// [U8_STATIC_1]!
-    #[should_panic(expected = "assertion failed: !ptr::eq(&U8_STATIC_1, &U8_CONST)")]
     #[test]
-    fn u8_global_const_and_global_static_release() {
-        assert!(!ptr::eq(&U8_STATIC_1, &U8_CONST));
+    fn u8s_ndd_new_concat() {
+        assert_eq!(U8S_NDD_CONCAT.get(), b"ABCD");
     }
-    #[cfg(any(debug_assertions, miri))]
-    /// In debug/MIRI, [ARR_CONST] and [ARR_STATIC_1] have unique/separate addresses!
+
+    #[cfg(not(feature = "terse-panics"))]
     #[test]
-    fn u8_global_const_global_and_static_debug_and_miri() {
-        assert!(!ptr::eq(&U8_STATIC_1, &U8_CONST));
+    #[should_panic(expected = "N is 3, but the parts have 4 byte(s): N is 1 too small.")]
+    fn u8s_ndd_new_concat_wrong_len() {
+        let _ = NonDeDuplicated::<[u8; 3]>::new_concat(&[b"AB", b"CD"]);
     }
 
-    #[cfg(not(miri))]
     #[test]
-    #[should_panic(expected = "assertion failed: !ptr::eq(STR_CONST_FROM_BYTE_ARRAY_HI, \"Hi\")")]
-    fn str_global_byte_slice_const_and_local_str_release_and_debug() {
-        assert!(!ptr::eq(STR_CONST_FROM_BYTE_ARRAY_HI, "Hi"));
+    fn copy_into() {
+        let mut dst = [0u8; 4];
+        assert_eq!(STR_NDD_CIAO.copy_into(&mut dst), 4);
+        assert_eq!(&dst, b"Ciao");
+
+        let mut dst = [b'-'; 5];
+        assert_eq!(U8S_NDD_ABC.copy_into(&mut dst), 3);
+        assert_eq!(&dst, b"abc--");
     }
-    #[cfg(miri)]
+
     #[test]
-    fn str_global_byte_slice_const_and_local_str_miri() {
-        assert!(!ptr::eq(STR_CONST_FROM_BYTE_ARRAY_HI, "Hi"));
+    #[should_panic(expected = "The destination is too small.")]
+    fn copy_into_too_small() {
+        STR_NDD_CIAO.copy_into(&mut [0u8; 3]);
     }
 
-    /// This is the same for all three: release, debug AND miri!
     #[test]
-    fn str_global_byte_by_byte_const_and_local_static() {
-        assert!(ptr::eq(STR_CONST_FROM_BYTE_STRING_HELLO, "Hello"));
+    fn eq_const_str() {
+        static IS_CIAO: bool = STR_NDD_CIAO.eq_const_str("Ciao");
+        static IS_HOLA: bool = STR_NDD_CIAO.eq_const_str("Hola");
+        assert!(IS_CIAO);
+        assert!(!IS_HOLA);
+        assert!(!STR_NDD_CIAO.eq_const_str("Cia"));
     }
 
-    static STR_STATIC: &str = "Ciao";
+    #[test]
+    fn empty() {
+        static STR_NDD_EMPTY: NonDeDuplicatedStr<0> = NonDeDuplicatedStr::empty();
+        static U8S_NDD_EMPTY: NonDeDuplicated<[u8; 0]> = NonDeDuplicated::empty();
+        assert_eq!(STR_NDD_EMPTY.get(), "");
+        assert_eq!(U8S_NDD_EMPTY.get(), &[]);
+    }
 
-    #[cfg(not(miri))]
-    #[should_panic(expected = "assertion failed: !ptr::eq(local_const_based_slice, STR_STATIC)")]
     #[test]
-    fn str_local_const_based_and_global_static_release_and_debug() {
-        str_local_const_based_and_global_static_impl();
+    fn eq_ignore_ascii_case() {
+        assert!(STR_NDD_CIAO.eq_ignore_ascii_case("Ciao"));
+        assert!(STR_NDD_CIAO.eq_ignore_ascii_case("CIAO"));
+        assert!(STR_NDD_CIAO.eq_ignore_ascii_case("cIaO"));
+        assert!(!STR_NDD_CIAO.eq_ignore_ascii_case("Cia"));
+        assert!(!STR_NDD_CIAO.eq_ignore_ascii_case("Ciau"));
+        // Only ASCII letters are folded.
+        assert!(STR_NDD_CAFE.eq_ignore_ascii_case("CAFé"));
+        assert!(!STR_NDD_CAFE.eq_ignore_ascii_case("CAFÉ"));
     }
-    #[cfg(miri)]
+
+    static STR_NDD_LINES: NonDeDuplicatedStr<8> = NonDeDuplicatedStr::new("a=1\nb=2\n");
+    static STR_NDD_CSV: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("x,y,z");
     #[test]
-    fn str_local_const_based_and_global_static_miri() {
-        str_local_const_based_and_global_static_impl();
+    fn str_ndd_lines_and_split() {
+        assert!(STR_NDD_LINES.lines().eq(["a=1", "b=2"]));
+        assert!(STR_NDD_CSV.split(',').eq(["x", "y", "z"]));
     }
-    fn str_local_const_based_and_global_static_impl() {
-        const LOCAL_CONST_ARR: [u8; 4] = [b'C', b'i', b'a', b'o'];
-        let local_const_based_slice: &str = str::from_utf8(&LOCAL_CONST_ARR).unwrap();
-        assert!(!ptr::eq(local_const_based_slice, STR_STATIC));
+
+    #[test]
+    fn str_ndd_char_indices() {
+        assert!(
+            STR_NDD_CAFE
+                .char_indices()
+                .eq([(0, 'c'), (1, 'a'), (2, 'f'), (3, 'é')])
+        );
     }
 
-    mod cross_module_static {
-        pub static STATIC_OPT_U8_A: Option<u8> = Some(b'A');
+    static ASCII_NDD_TOKEN: NonDeDuplicatedAscii<5> = NonDeDuplicatedAscii::new("TOKEN");
+    #[test]
+    fn ascii_ndd() {
+        assert_eq!(ASCII_NDD_TOKEN.get(), "TOKEN");
+        assert!(!ptr::eq(ASCII_NDD_TOKEN.get(), "TOKEN"));
     }
-    mod cross_module_const {
-        use core::ptr;
-        pub const CONST_OPT_U8_A: Option<u8> = Some(b'A');
 
-        #[cfg(not(any(debug_assertions, miri)))]
-        #[test]
-        #[should_panic(
-            expected = "assertion failed: !ptr::eq(&CONST_OPT_U8_A, &super::cross_module_static::STATIC_OPT_U8_A)"
-        )]
-        fn option_u8_global_const_global_static_release() {
-            assert!(!ptr::eq(
-                &CONST_OPT_U8_A,
-                &super::cross_module_static::STATIC_OPT_U8_A
-            ));
-        }
-        #[cfg(any(debug_assertions, miri))]
-        #[test]
-        fn option_u8_global_const_global_static_debug_and_miri() {
-            assert!(!ptr::eq(
-                &CONST_OPT_U8_A,
-                &super::cross_module_static::STATIC_OPT_U8_A
-            ));
+    #[test]
+    #[should_panic(expected = "The string must contain ASCII bytes only.")]
+    fn ascii_ndd_non_ascii() {
+        let _ = NonDeDuplicatedAscii::<5>::new("café");
+    }
+
+    static STR_NDD_HELLO: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("Hello");
+    static STR_NDD_HOLA: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Hola");
+    static STR_NDD_WORLD: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("World");
+    #[test]
+    fn str_ndd_eq() {
+        assert!(STR_NDD_HI == STR_NDD_HELLO);
+        assert!(STR_NDD_HI != STR_NDD_WORLD);
+        assert!(STR_NDD_HI != STR_NDD_HOLA);
+    }
+
+    static STR_NDD_HELLO_2: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("Hello");
+    /// Laws of [Eq], checked for all pairs (and triples).
+    #[test]
+    fn str_ndd_eq_laws() {
+        let all = [
+            &STR_NDD_HI,
+            &STR_NDD_HELLO,
+            &STR_NDD_HELLO_2,
+            &STR_NDD_WORLD,
+        ];
+        for a in all {
+            assert!(a == a);
+            for b in all {
+                assert_eq!(a == b, b == a);
+                for c in all {
+                    if a == b && b == c {
+                        assert!(a == c);
+                    }
+                }
+            }
         }
     }
-}
 
-#[cfg(test)]
-mod tests_with_ndd {
-    use super::*;
-    use crate::tests_shared::{STR_CONST_FROM_BYTE_ARRAY_HI, STR_CONST_FROM_BYTE_STRING_HELLO};
-    use core::ptr;
+    static U32_NDD_1_AGAIN: NonDeDuplicated<u32> = NonDeDuplicated::new(1);
+    // A false positive: there is no mutation API, so the inner Cell doesn't change hashes.
+    #[allow(clippy::mutable_key_type)]
+    #[test]
+    fn str_ndd_hash_map_key() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(&STR_NDD_HELLO, 1);
+        map.insert(&STR_NDD_WORLD, 2);
+        // Equal by value, so the same key, even though at a different address.
+        assert_eq!(map.insert(&STR_NDD_HELLO_2, 3), Some(1));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&STR_NDD_HELLO], 3);
 
-    const U8_CONST: u8 = b'A';
-    static U8_STATIC_1: u8 = b'A';
-    static U8_STATIC_2: u8 = b'A';
+        let mut u32_map = std::collections::HashMap::new();
+        u32_map.insert(&U32_NDD_1, "one");
+        assert_eq!(u32_map.get(&&U32_NDD_1_AGAIN), Some(&"one"));
+    }
 
-    #[allow(dead_code)]
-    const fn expect_sync_ref<T: Sync>() {}
-    #[allow(dead_code)]
-    const _: () = expect_sync_ref::<NonDeDuplicated<u8>>();
+    static CSTR_NDD_CROSS: NonDeDuplicatedCStr<6> = NonDeDuplicatedCStr::new(c"Cross");
+    #[test]
+    fn cstr_ndd_new() {
+        assert_eq!(CSTR_NDD_CROSS.get(), c"Cross");
+        assert!(!ptr::eq(CSTR_NDD_CROSS.get(), c"Cross"));
+    }
 
-    static U8_NDD: NonDeDuplicated<u8> = NonDeDuplicated::new(U8_CONST);
-    static U8_NDD_REF: &u8 = U8_NDD.get();
     #[test]
-    fn u8_global_const_and_ndd() {
-        assert!(!ptr::eq(U8_NDD_REF, &U8_CONST));
-        assert!(!ptr::eq(U8_NDD_REF, &U8_STATIC_1));
-        assert!(!ptr::eq(U8_NDD_REF, &U8_STATIC_2));
+    fn cstr_ndd_content_and_nul_index() {
+        static CROSS_NUL_INDEX: usize = CSTR_NDD_CROSS.content_and_nul_index().1;
+        assert_eq!(CROSS_NUL_INDEX, 5);
+        let (content, nul_index) = CSTR_NDD_CROSS.content_and_nul_index();
+        assert_eq!((content, nul_index), (&b"Cross"[..], 5));
+        assert_eq!(CSTR_NDD_CROSS.as_array()[nul_index], 0);
+        assert!(ptr::eq(content, &CSTR_NDD_CROSS.as_array()[..5]));
     }
 
-    static STR_NDD_HI: NonDeDuplicatedStr<5> = NonDeDuplicatedStr::new("Hello");
     #[test]
-    fn str_ndd_hi() {
-        assert!(!ptr::eq(STR_NDD_HI.get(), "Hi"));
-        assert!(!ptr::eq(STR_NDD_HI.get(), STR_CONST_FROM_BYTE_ARRAY_HI));
-        assert!(!ptr::eq(STR_NDD_HI.get(), STR_CONST_FROM_BYTE_STRING_HELLO));
+    #[should_panic(expected = "The C string must end with a NUL byte.")]
+    fn cstr_ndd_new_from_bytes_without_nul() {
+        let _ = NonDeDuplicatedCStr::new_from_bytes(*b"Cross");
     }
 
-    static STR_NDD_CIAO: NonDeDuplicatedStr<4> = NonDeDuplicatedStr::new("Ciao");
     #[test]
-    fn str_local_const_based_and_str_ndd() {
-        const LOCAL_CONST_ARR: [u8; 4] = [b'C', b'i', b'a', b'o'];
-        let local_const_based_slice: &str = str::from_utf8(&LOCAL_CONST_ARR).unwrap();
-        assert!(!ptr::eq(local_const_based_slice, STR_NDD_CIAO.get()));
+    #[should_panic(expected = "The C string must not contain any interior NUL bytes.")]
+    fn cstr_ndd_new_from_bytes_interior_nul() {
+        let _ = NonDeDuplicatedCStr::new_from_bytes(*b"Cr\0ss\0");
+    }
+
+    static CSTR_NDD_FROM_SLICE: NonDeDuplicatedCStr<6> =
+        NonDeDuplicatedCStr::new_from_byte_slice(b"Slice\0".as_slice());
+    #[test]
+    fn cstr_ndd_new_from_byte_slice() {
+        assert_eq!(CSTR_NDD_FROM_SLICE.get(), c"Slice");
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(expected = "Target length is 1 byte too small.")]
+    fn cstr_ndd_new_from_byte_slice_wrong_len() {
+        let _ = NonDeDuplicatedCStr::<5>::new_from_byte_slice(b"Slice\0");
+    }
+
+    #[test]
+    #[should_panic(expected = "The C string must end with a NUL byte.")]
+    fn cstr_ndd_new_from_byte_slice_without_nul() {
+        let _ = NonDeDuplicatedCStr::<5>::new_from_byte_slice(b"Slice");
+    }
+
+    #[test]
+    #[should_panic(expected = "The C string must not contain any interior NUL bytes.")]
+    fn cstr_ndd_new_from_str_interior_nul() {
+        let _ = NonDeDuplicatedCStr::<6>::new_from_str("Cr\0ss");
+    }
+
+    #[test]
+    fn cstr_ndd_new_from_str_multi_byte() {
+        assert_eq!(CSTR_NDD_CAFE.get(), c"café");
+        assert_eq!(CSTR_NDD_CAFE.get().to_str(), Ok("café"));
+        assert_eq!(CSTR_NDD_CAFE.as_array(), "café\0".as_bytes());
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(
+        expected = "NonDeDuplicatedCStr with N=5 holds 4 string byte(s) (plus the trailing NUL byte), but got 5."
+    )]
+    fn cstr_ndd_new_from_str_multi_byte_n_too_small() {
+        // "café" has 5 bytes, so N must be 6.
+        let _ = NonDeDuplicatedCStr::<5>::new_from_str("café");
+    }
+
+    #[cfg(not(feature = "terse-panics"))]
+    #[test]
+    #[should_panic(
+        expected = "NonDeDuplicatedCStr with N=7 holds 6 string byte(s) (plus the trailing NUL byte), but got 5."
+    )]
+    fn cstr_ndd_new_from_str_multi_byte_n_too_large() {
+        let _ = NonDeDuplicatedCStr::<7>::new_from_str("café");
+    }
+
+    #[cfg(feature = "terse-panics")]
+    #[test]
+    #[should_panic(expected = "Length mismatch.")]
+    fn terse_panics_str_ndd_new() {
+        let _ = NonDeDuplicatedStr::<4>::new("café");
+    }
+
+    #[cfg(feature = "terse-panics")]
+    #[test]
+    #[should_panic(expected = "Length mismatch.")]
+    fn terse_panics_str_ndd_new_checked() {
+        let _ = NonDeDuplicatedStr::<3>::new_checked("Checked");
+    }
+
+    #[cfg(feature = "terse-panics")]
+    #[test]
+    #[should_panic(expected = "Length mismatch.")]
+    fn terse_panics_cstr_ndd_new_from_str() {
+        let _ = NonDeDuplicatedCStr::<7>::new_from_str("café");
+    }
+
+    /// This leaks on purpose. So, with `alloc` feature, run MIRI with `-Zmiri-ignore-leaks`.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn intern_str() {
+        let x1 = crate::intern_str("x");
+        let x2 = crate::intern_str("x");
+        assert_eq!(x1, "x");
+        assert!(!ptr::eq(x1, x2));
+        assert!(!ptr::eq(x1, "x"));
+
+        let empty1 = crate::intern_str("");
+        let empty2 = crate::intern_str("");
+        assert_eq!(empty1, "");
+        assert!(!ptr::addr_eq(empty1, empty2));
+    }
+
+    /// This leaks on purpose, like [intern_str].
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_ref() {
+        let runtime_value = U8_NDD.get().wrapping_add(1);
+        let r1: &'static u8 = NonDeDuplicated::new(runtime_value).into_ref();
+        let r2: &'static u8 = NonDeDuplicated::new(runtime_value).into_ref();
+        assert_eq!(*r1, b'B');
+        assert!(!ptr::eq(r1, r2));
+    }
+
+    #[cfg(feature = "std")]
+    static STR_NDD_MANIFEST: NonDeDuplicatedStr<10> = NonDeDuplicatedStr::new("Cargo.toml");
+    /// `cargo test` runs in the package directory.
+    #[cfg(feature = "std")]
+    #[test]
+    fn as_path_and_as_os_str() {
+        let manifest = std::fs::read_to_string(STR_NDD_MANIFEST.as_path()).unwrap();
+        assert!(manifest.contains("name = \"ndd\""));
+        assert_eq!(STR_NDD_MANIFEST.as_os_str(), "Cargo.toml");
+    }
+
+    #[test]
+    fn from_cstr_ndd_for_static_cstr() {
+        let c: &'static CStr = (&CSTR_NDD_CROSS).into();
+        assert_eq!(c, c"Cross");
+        assert!(ptr::eq(c, CSTR_NDD_CROSS.get()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cstr_to_string_lossy() {
+        static CSTR_NDD_NOT_UTF8: NonDeDuplicatedCStr<4> =
+            NonDeDuplicatedCStr::new_from_bytes(*b"A\xFFB\0");
+
+        assert_eq!(CSTR_NDD_SAME_1.to_string_lossy(), "same");
+        assert_eq!(CSTR_NDD_NOT_UTF8.to_string_lossy(), "A\u{FFFD}B");
+    }
+
+    fn cstr_bytes(c: impl AsRef<CStr>) -> usize {
+        c.as_ref().to_bytes().len()
+    }
+    #[test]
+    fn as_ref_cstr() {
+        assert_eq!(cstr_bytes(&CSTR_NDD_CROSS), 5);
+        let c: &CStr = CSTR_NDD_CROSS.as_ref();
+        assert_eq!(c.to_bytes(), b"Cross");
+        assert!(ptr::eq(c, CSTR_NDD_CROSS.get()));
+    }
+
+    static CSTR_NDD_UNCHECKED: NonDeDuplicatedCStr<4> =
+        unsafe { NonDeDuplicatedCStr::new_from_bytes_unchecked(*b"Unc\0") };
+    #[test]
+    fn cstr_ndd_new_from_bytes_unchecked() {
+        assert_eq!(CSTR_NDD_UNCHECKED.get(), c"Unc");
+    }
+
+    #[test]
+    fn get_unchecked() {
+        let s = unsafe { STR_NDD_CIAO.get_unchecked() };
+        assert!(ptr::eq(s, STR_NDD_CIAO.get()));
+        let c = unsafe { CSTR_NDD_CIAO.get_unchecked() };
+        assert!(ptr::eq(c, CSTR_NDD_CIAO.get()));
+        assert_eq!(c, c"Ciao");
+    }
+
+    /// Breaks the safety contract of `new_from_bytes_unchecked` on purpose, to reach the panic
+    /// in `get()`. (That only reads the bytes, so there is no undefined behavior.)
+    static CSTR_NDD_MALFORMED: NonDeDuplicatedCStr<4> =
+        unsafe { NonDeDuplicatedCStr::new_from_bytes_unchecked(*b"Mal!") };
+    #[test]
+    #[should_panic(expected = "The bytes of a C string wrapper are not a well-formed C string.")]
+    fn cstr_ndd_get_malformed() {
+        let _ = CSTR_NDD_MALFORMED.get();
+    }
+
+    static OPTION_NDD_SOME: NonDeDuplicated<Option<u8>> = NonDeDuplicated::new(Some(b'X'));
+    static OPTION_NDD_NONE: NonDeDuplicated<Option<u8>> = NonDeDuplicated::new(None);
+    #[test]
+    fn option_ndd_get_inner() {
+        let inner = OPTION_NDD_SOME.get_inner().unwrap();
+        assert_eq!(*inner, b'X');
+        assert!(ptr::eq(inner, OPTION_NDD_SOME.get().as_ref().unwrap()));
+        assert_eq!(OPTION_NDD_NONE.get_inner(), None);
+    }
+
+    #[test]
+    fn cstr_ndd_try_from_bytes() {
+        let bytes: &[u8] = b"Try\0";
+        let cstr = NonDeDuplicatedCStr::<4>::try_from(bytes).unwrap();
+        assert_eq!(cstr.get(), c"Try");
+        // Instead of Box::leak(), which MIRI would report as a memory leak.
+        core::mem::forget(cstr);
+    }
+
+    #[test]
+    fn cstr_ndd_try_from_bytes_wrong_length() {
+        let bytes: &[u8] = b"Try\0";
+        let Err(e) = NonDeDuplicatedCStr::<5>::try_from(bytes) else {
+            panic!("Expected an error.")
+        };
+        assert_eq!(
+            e,
+            CStrBytesError::WrongLength {
+                expected: 5,
+                actual: 4
+            }
+        );
+        assert_eq!(
+            e.to_string(),
+            "Expected 5 byte(s) (including the trailing NUL byte), but got 4."
+        );
+    }
+
+    #[test]
+    fn cstr_ndd_try_from_bytes_interior_nul() {
+        let bytes: &[u8] = b"T\0y\0";
+        let Err(e) = NonDeDuplicatedCStr::<4>::try_from(bytes) else {
+            panic!("Expected an error.")
+        };
+        assert!(matches!(e, CStrBytesError::Malformed(_)));
+    }
+
+    const _: () = assert!(crate::cstr_capacity_for_str::<4>() == 5);
+    const _: () = assert!(crate::cstr_capacity_for_str::<0>() == 1);
+    static CSTR_NDD_PAIRED: NonDeDuplicatedCStr<{ crate::cstr_capacity_for_str::<4>() }> =
+        NonDeDuplicatedCStr::new_from_str("Ciao");
+    #[test]
+    fn cstr_capacity_for_str() {
+        assert_eq!(
+            CSTR_NDD_PAIRED.get().to_bytes(),
+            STR_NDD_CIAO.get().as_bytes()
+        );
+    }
+
+    #[test]
+    fn cstr_array_len_for_bytes() {
+        assert_eq!(crate::cstr_array_len_for_bytes(b"abc"), 4);
+        assert_eq!(crate::cstr_array_len_for_bytes(b"abc\0"), 4);
+        assert_eq!(crate::cstr_array_len_for_bytes(b""), 1);
+    }
+
+    static CSTR_NDD_SAME_1: NonDeDuplicatedCStr<5> = NonDeDuplicatedCStr::new(c"same");
+    static CSTR_NDD_SAME_2: NonDeDuplicatedCStr<5> = NonDeDuplicatedCStr::new(c"same");
+    /// The same in release, debug and MIRI.
+    #[test]
+    fn cstr_ndd_same_content_distinct_ptrs() {
+        let ptr_1 = CSTR_NDD_SAME_1.get().as_ptr();
+        let ptr_2 = CSTR_NDD_SAME_2.get().as_ptr();
+        assert!(!ptr::eq(ptr_1, ptr_2));
+        assert!(!ptr::eq(ptr_1, c"same".as_ptr()));
+        assert!(!ptr::eq(ptr_2, c"same".as_ptr()));
+    }
+
+    #[test]
+    fn str_ndd_try_from_cstr_ndd() {
+        let cstr = NonDeDuplicatedCStr::<5>::new(c"Utf8");
+        let s = NonDeDuplicatedStr::<4>::try_from(cstr).unwrap();
+        assert_eq!(s.get(), "Utf8");
+        // Instead of Box::leak(), which MIRI would report as a memory leak.
+        core::mem::forget(s);
+    }
+
+    #[test]
+    fn str_ndd_try_from_cstr_ndd_not_utf8() {
+        let cstr = NonDeDuplicatedCStr::<3>::new_from_bytes([b'A', 0xC3, 0]);
+        let e = NonDeDuplicatedStr::<2>::try_from(cstr).unwrap_err();
+        assert!(matches!(e, CStrToStrError::NotUtf8(_)));
+    }
+
+    #[test]
+    fn str_ndd_try_from_cstr_ndd_wrong_length() {
+        let cstr = NonDeDuplicatedCStr::<5>::new(c"Utf8");
+        let e = NonDeDuplicatedStr::<5>::try_from(cstr).unwrap_err();
+        assert_eq!(
+            e,
+            CStrToStrError::WrongLength {
+                expected: 5,
+                actual: 4
+            }
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        use core::error::Error;
+
+        let bytes: &[u8] = b"Try";
+        let e = NonDeDuplicatedCStr::<3>::try_from(bytes).unwrap_err();
+        let source = e.source().unwrap().to_string();
+        assert_eq!(
+            e.to_string(),
+            format!("Not a well-formed C string: {source}")
+        );
+
+        let e = CStrToStrError::WrongLength {
+            expected: 2,
+            actual: 3,
+        };
+        assert_eq!(
+            e.to_string(),
+            "Expected a C string of 2 byte(s) (without the trailing NUL byte), but got 3."
+        );
+        assert!(e.source().is_none());
+
+        let cstr = NonDeDuplicatedCStr::<3>::new_from_bytes([b'A', 0xC3, 0]);
+        let e = NonDeDuplicatedStr::<2>::try_from(cstr).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Not valid UTF-8: incomplete utf-8 byte sequence from index 1"
+        );
+    }
+
+    fn bytes_len(bytes: impl AsRef<[u8]>) -> usize {
+        bytes.as_ref().len()
+    }
+    static CSTR_NDD_CIAO: NonDeDuplicatedCStr<5> = NonDeDuplicatedCStr::new_from_bytes(*b"Ciao\0");
+    #[test]
+    fn as_ref_u8s() {
+        assert_eq!(STR_NDD_CIAO.as_ref(), b"Ciao");
+        assert_eq!(bytes_len(&STR_NDD_CIAO), 4);
+        assert_eq!(AsRef::<[u8]>::as_ref(&CSTR_NDD_CIAO), b"Ciao");
+        assert_eq!(bytes_len(&CSTR_NDD_CIAO), 4);
     }
 
     #[test]
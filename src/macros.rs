@@ -0,0 +1,226 @@
+/// Construct a [NonDeDuplicated](crate::NonDeDuplicated)`<[u8; N]>` whose byte at each index `i`
+/// is computed by the given closure-like expression. Usable in `static` initializers, so the table
+/// is computed at compile time - for example, a CRC table.
+///
+/// (A `const fn` accepting `fn(usize) -> u8` is not possible: `const fn` can't call function
+/// pointers on `stable` Rust.)
+/// ```rust
+/// use ndd::NonDeDuplicated;
+///
+/// static SQUARES: NonDeDuplicated<[u8; 4]> = ndd::ndd_bytes_with!(4, |i| (i * i) as u8);
+/// assert_eq!(SQUARES.get(), &[0, 1, 4, 9]);
+/// ```
+#[macro_export]
+macro_rules! ndd_bytes_with {
+    ($n:expr, |$i:ident| $byte:expr) => {
+        $crate::NonDeDuplicated::<[u8; $n]>::new({
+            let mut arr = [0u8; $n];
+            let mut $i: usize = 0;
+            while $i < $n {
+                arr[$i] = $byte;
+                $i += 1;
+            }
+            arr
+        })
+    };
+}
+
+/// Construct a [NonDeDuplicated](crate::NonDeDuplicated)`<[u8; N]>` by mapping each byte of the
+/// given `[u8; N]` with a closure-like expression. Usable in `static` initializers (for the same
+/// reason as [ndd_bytes_with!] is a macro).
+/// ```rust
+/// use ndd::NonDeDuplicated;
+///
+/// const TABLE: [u8; 3] = [0x00, 0x0F, 0xFF];
+/// static COMPLEMENT: NonDeDuplicated<[u8; 3]> = ndd::ndd_bytes_mapped!(TABLE, |b| !b);
+/// assert_eq!(COMPLEMENT.get(), &[0xFF, 0xF0, 0x00]);
+/// ```
+#[macro_export]
+macro_rules! ndd_bytes_mapped {
+    ($src:expr, |$b:ident| $byte:expr) => {
+        $crate::NonDeDuplicated::new({
+            let mut arr = $src;
+            let mut i: usize = 0;
+            while i < arr.len() {
+                let $b: u8 = arr[i];
+                arr[i] = $byte;
+                i += 1;
+            }
+            arr
+        })
+    };
+}
+
+/// Construct a [NonDeDuplicated](crate::NonDeDuplicated)`<[u8; N]>` from a `const` byte slice
+/// (`&[u8]`), with `N` computed (at compile time) from the slice's length.
+/// ```rust
+/// use ndd::NonDeDuplicated;
+///
+/// const MAGIC: &[u8] = b"\x7FELF";
+/// static MAGIC_NDD: NonDeDuplicated<[u8; MAGIC.len()]> = ndd::ndd_bytes_from_const!(MAGIC);
+/// assert_eq!(MAGIC_NDD.get(), MAGIC);
+/// ```
+#[macro_export]
+macro_rules! ndd_bytes_from_const {
+    ($slice:expr) => {
+        $crate::NonDeDuplicated::<[u8; { $slice.len() }]>::new_concat(&[$slice])
+    };
+}
+
+/// A [NonDeDuplicatedStr](crate::NonDeDuplicatedStr) holding the [concat!]-enation of the given
+/// literals. `N` is computed automatically (there is no `N` to spell, nor to maintain).
+///
+/// Either declare a `static`:
+/// ```rust
+/// ndd::ndd_concat_str!(pub static KEY = "user.", 42, "!");
+/// assert_eq!(KEY.get(), "user.42!");
+/// ```
+/// Or get a `&'static` reference to a hidden `static` (one per macro call site):
+/// ```rust
+/// let key: &'static ndd::NonDeDuplicatedStr<8> = ndd::ndd_concat_str!("user.", 42, "!");
+/// assert_eq!(key.get(), "user.42!");
+/// ```
+#[macro_export]
+macro_rules! ndd_concat_str {
+    ($vis:vis static $name:ident = $($s:expr),+ $(,)?) => {
+        $vis static $name: $crate::NonDeDuplicatedStr<{ ::core::concat!($($s),+).len() }> =
+            $crate::NonDeDuplicatedStr::new(::core::concat!($($s),+));
+    };
+    ($($s:expr),+ $(,)?) => {{
+        static S: $crate::NonDeDuplicatedStr<{ ::core::concat!($($s),+).len() }> =
+            $crate::NonDeDuplicatedStr::new(::core::concat!($($s),+));
+        &S
+    }};
+}
+
+/// Declare a `static` group of related [NonDeDuplicatedStr](crate::NonDeDuplicatedStr)-s, each
+/// sized automatically (so there is no `N` to maintain). It expands to a struct with one field per
+/// string, an accessor (returning `&str`) per field, and the `static` itself.
+/// ```rust
+/// ndd::ndd_group_str! {
+///     pub static COLORS: Colors {
+///         red: "red",
+///         green: "green",
+///     }
+/// }
+/// assert_eq!(COLORS.red(), "red");
+/// assert_eq!(COLORS.green(), "green");
+/// ```
+#[macro_export]
+macro_rules! ndd_group_str {
+    (
+        $vis:vis static $static_name:ident: $struct_name:ident {
+            $($field:ident: $s:expr),+ $(,)?
+        }
+    ) => {
+        $vis struct $struct_name {
+            $($field: $crate::NonDeDuplicatedStr<{ $s.len() }>,)+
+        }
+
+        impl $struct_name {
+            $(
+                $vis const fn $field(&self) -> &str {
+                    self.$field.get()
+                }
+            )+
+        }
+
+        $vis static $static_name: $struct_name = $struct_name {
+            $($field: $crate::NonDeDuplicatedStr::new($s),)+
+        };
+    };
+}
+
+/// Generate a `#[test]` function (with the given name) that asserts that the given `ndd` `static`
+/// variables (of any `ndd` types) have distinct addresses.
+/// ```rust
+/// static RED: ndd::NonDeDuplicatedStr<3> = ndd::NonDeDuplicatedStr::new("red");
+/// static ONE: ndd::NonDeDuplicated<u8> = ndd::NonDeDuplicated::new(1);
+///
+/// ndd::test_distinct!(red_and_one_distinct: RED, ONE);
+/// ```
+/// This is a test (rather than a compile time assertion), because `const` evaluation can't compare
+/// addresses of `static` variables (as of mid 2025, comparing pointers is not `const` on
+/// `stable`). And the addresses get final only when linking.
+#[macro_export]
+macro_rules! test_distinct {
+    ($name:ident: $($s:expr),+ $(,)?) => {
+        #[test]
+        fn $name() {
+            let ptrs = [$($s.as_erased_ptr()),+];
+            let names = [$(::core::stringify!($s)),+];
+            for i in 0..ptrs.len() {
+                for j in i + 1..ptrs.len() {
+                    ::core::assert!(
+                        !::core::ptr::eq(ptrs[i], ptrs[j]),
+                        "{} and {} share an address.",
+                        names[i],
+                        names[j]
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NonDeDuplicated;
+
+    static IDENTITY: NonDeDuplicated<[u8; 256]> = crate::ndd_bytes_with!(256, |i| i as u8);
+
+    #[test]
+    fn bytes_with_identity() {
+        for (i, byte) in IDENTITY.get().iter().enumerate() {
+            assert_eq!(*byte, i as u8);
+        }
+    }
+
+    static SHIFTED: NonDeDuplicated<[u8; 3]> = crate::ndd_bytes_mapped!(*b"HAL", |b| b + 1);
+
+    #[test]
+    fn bytes_mapped() {
+        assert_eq!(SHIFTED.get(), b"IBM");
+    }
+
+    const TRIPLE: &[u8] = &[3, 2, 1];
+    static FROM_CONST: NonDeDuplicated<[u8; 3]> = crate::ndd_bytes_from_const!(TRIPLE);
+
+    #[test]
+    fn bytes_from_const() {
+        assert_eq!(FROM_CONST.get(), &[3, 2, 1]);
+        assert!(!core::ptr::eq(FROM_CONST.get().as_slice(), TRIPLE));
+    }
+
+    crate::ndd_concat_str!(static CONCATENATED = "foo", "bar");
+
+    #[test]
+    fn concat_str() {
+        assert_eq!(CONCATENATED.get(), "foobar");
+        let _: &crate::NonDeDuplicatedStr<6> = &CONCATENATED;
+    }
+
+    #[test]
+    fn concat_str_expr() {
+        assert!(crate::ndd_concat_str!("foo", "bar").get() == "foobar");
+        let _: &'static crate::NonDeDuplicatedStr<6> = crate::ndd_concat_str!("foo", "bar");
+    }
+
+    crate::ndd_group_str! {
+        static GROUP: Group {
+            first: "Rosso",
+            second: "Verde",
+            third: "Blu",
+        }
+    }
+
+    crate::test_distinct!(distinct: GROUP.first, GROUP.second, CONCATENATED, IDENTITY);
+
+    #[test]
+    fn group_str() {
+        assert_eq!(GROUP.first(), "Rosso");
+        assert_eq!(GROUP.second(), "Verde");
+        assert_eq!(GROUP.third(), "Blu");
+        crate::testing::within_process_distinct(&[GROUP.first(), GROUP.second(), GROUP.third()]);
+    }
+}
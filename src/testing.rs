@@ -0,0 +1,47 @@
+//! Helpers for tests that compare addresses.
+//!
+//! Addresses differ between runs (because of ASLR - address space layout randomization), and
+//! between builds. So never hard-code or persist them. Only compare addresses captured in the same
+//! process (run).
+
+/// Assert that all given references point to distinct addresses. Only the addresses are compared
+/// (and not any metadata, like slice lengths), as with [core::ptr::addr_eq].
+///
+/// Panics (naming the indices of the first colliding pair) otherwise.
+#[track_caller]
+pub fn within_process_distinct<T: ?Sized>(refs: &[&T]) {
+    let mut i = 0;
+    while i < refs.len() {
+        let mut j = i + 1;
+        while j < refs.len() {
+            if core::ptr::addr_eq(refs[i], refs[j]) {
+                panic!("References at indices {} and {} share an address.", i, j);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::within_process_distinct;
+    use crate::{NonDeDuplicated, NonDeDuplicatedStr};
+
+    static U8_NDD_1: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+    static U8_NDD_2: NonDeDuplicated<u8> = NonDeDuplicated::new(b'R');
+    static STR_NDD_1: NonDeDuplicatedStr<2> = NonDeDuplicatedStr::new("Re");
+    static STR_NDD_2: NonDeDuplicatedStr<2> = NonDeDuplicatedStr::new("Re");
+
+    #[test]
+    fn distinct() {
+        within_process_distinct(&[U8_NDD_1.get(), U8_NDD_2.get()]);
+        within_process_distinct(&[STR_NDD_1.get(), STR_NDD_2.get()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "References at indices 0 and 2 share an address.")]
+    fn collision() {
+        within_process_distinct(&[U8_NDD_1.get(), U8_NDD_2.get(), U8_NDD_1.get()]);
+    }
+}